@@ -0,0 +1,79 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    let mut galar = Galar::new("Camera 2D Example", 400, 400, 60, None)?
+        .with_camera(Camera2D::centered(400, 400).with_bounds(Vec2::ZERO, Vec2::splat(800.0)));
+
+    println!("\nArrastra con el Mouse, rueda para zoom, 'Q'/'E' rotan, 'R' reinicia, 'ESC' para cerrar 😉\n");
+
+    galar.add_plugin(CameraView::new(8));
+
+    galar.run()
+}
+
+struct CameraView {
+    tiles: Vec<Shape>,
+    last_mouse: Option<Vec2>,
+}
+
+impl CameraView {
+    fn new(cells: usize) -> Self {
+        let size = 800.0 / cells as f32;
+        let mut tiles = Vec::with_capacity(cells * cells);
+        for idx in 0..(cells * cells) {
+            let col = idx % cells;
+            let row = idx / cells;
+            let t = idx as f32 / (cells * cells) as f32;
+            tiles.push(
+                Shape::quad((size * 0.9) as usize)
+                    .with_position(col as f32 * size, row as f32 * size)
+                    .with_color(Color::rainbow(t)),
+            );
+        }
+        Self {
+            tiles,
+            last_mouse: None,
+        }
+    }
+}
+
+impl PluginGalar for CameraView {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        let mouse = config
+            .get_mouse_position(MouseMode::Discard)
+            .map(|(x, y)| Vec2::new(x, y));
+
+        // Arrastrar para desplazar la vista
+        if config.is_mouse_down(MouseButton::Left)
+            && let (Some(current), Some(last)) = (mouse, self.last_mouse)
+        {
+            config.camera.pan(current - last);
+        }
+        self.last_mouse = mouse;
+
+        // Zoom alrededor del cursor
+        if let (Some((_, scroll)), Some(point)) = (config.get_scroll_wheel(), mouse) {
+            config.camera.zoom_at(point, 1.1f32.powf(scroll.signum()));
+        }
+
+        if config.is_key_down(Key::Q) {
+            config.camera.rotate(-0.02);
+        }
+        if config.is_key_down(Key::E) {
+            config.camera.rotate(0.02);
+        }
+        if config.is_key_down(Key::R) {
+            config.camera.reset();
+        }
+
+        for tile in &self.tiles {
+            draw_shape(config, tile);
+        }
+    }
+    fn init(&mut self, config: &mut ConfigGalar) {
+        config.set_background(Color::BLACK.to_hex());
+    }
+    fn name(&self) -> &str {
+        "Camera View"
+    }
+}
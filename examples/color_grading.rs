@@ -0,0 +1,64 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    // Opcional: cargo run --example color_grading -- ruta/al/look.cube
+    let lut = match std::env::args().nth(1) {
+        Some(path) => Lut3D::load(&path)?,
+        None => Lut3D::identity(17),
+    };
+
+    let grade = ColorGrade::new()
+        .with_contrast(1.1)
+        .with_saturation(1.2)
+        .with_curves(
+            Curve::s_curve(0.5),
+            Curve::identity(),
+            Curve::identity(),
+            Curve::new(&[(0.0, 0.08), (1.0, 0.95)]),
+        );
+
+    let mut galar = Galar::new("Color Grading Example", 400, 400, 60, None)?
+        .with_post_effect(grade)
+        .with_post_effect(LutGrade::new(lut));
+
+    println!("\n'Q'/'E' giran el tono, 'A'/'D' cambian la exposición, 'ESC' para cerrar 😉\n");
+
+    galar.add_plugin(ColorGrading);
+
+    galar.run()
+}
+
+struct ColorGrading;
+
+impl PluginGalar for ColorGrading {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        let dt = config.delta_time();
+        let (q, e) = (config.is_key_down(Key::Q), config.is_key_down(Key::E));
+        let (a, d) = (config.is_key_down(Key::A), config.is_key_down(Key::D));
+        if let Some(grade) = config.post.get_mut::<ColorGrade>(0) {
+            if q {
+                grade.hue_shift -= 90.0 * dt;
+            }
+            if e {
+                grade.hue_shift += 90.0 * dt;
+            }
+            if a {
+                grade.exposure -= dt;
+            }
+            if d {
+                grade.exposure += dt;
+            }
+        }
+
+        // Franjas con todo el círculo de tono y una rampa de grises
+        let (width, height) = config.size();
+        for x in 0..width {
+            let t = x as f32 / width as f32;
+            config.draw_rect(x, 0, 1, height * 3 / 4, Color::rainbow(t).to_hex());
+            config.draw_rect(x, height * 3 / 4, 1, height / 4, Color::lightness(t).to_hex());
+        }
+    }
+    fn name(&self) -> &str {
+        "Color Grading"
+    }
+}
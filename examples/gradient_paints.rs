@@ -0,0 +1,74 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    let mut galar = Galar::new("Gradient Paints Example", 400, 400, 60, None)?;
+
+    println!("\nPresiona 'ESC' para cerrar la ventana 😉\n");
+
+    galar.add_plugin(Paints::new());
+
+    galar.run()
+}
+
+struct Paints {
+    shapes: Vec<Shape>,
+}
+
+impl Paints {
+    fn new() -> Self {
+        let sunset = Gradient::new(&[Color::PURPLE, Color::RED, Color::ORANGE, Color::YELLOW]);
+
+        // Lineal en coordenadas del quad: gira junto con él
+        let linear = Shape::quad(120)
+            .with_paint(Paint::linear(Vec2::ZERO, Vec2::new(120.0, 0.0), sunset.clone()))
+            .with_position(40.0, 40.0)
+            .with_orgien(true);
+
+        // Radial con el foco desplazado hacia arriba a la izquierda
+        let radial = draw_circle(300.0, 100.0, 60.0, 48, Color::WHITE)
+            .new_shape()
+            .with_paint(
+                Paint::radial(Vec2::ZERO, 60.0, Gradient::new(&[Color::WHITE, Color::BLUE, Color::BLACK]))
+                    .with_focal(Vec2::new(-25.0, -25.0)),
+            );
+
+        // Cónico repetido: un arcoíris que da una vuelta completa
+        let conic = draw_circle(100.0, 300.0, 60.0, 48, Color::WHITE)
+            .new_shape()
+            .with_paint(Paint::conic(Vec2::ZERO, 0.0, Colormap::Turbo.gradient(16)));
+
+        // En espacio de pantalla el degradado queda fijo mientras el quad se mueve
+        let screen = Shape::quad(100)
+            .with_paint(
+                Paint::linear(
+                    Vec2::new(0.0, 220.0),
+                    Vec2::new(0.0, 260.0),
+                    sunset.with_spread(Spread::Mirror),
+                )
+                .with_space(PaintSpace::Screen),
+            )
+            .with_position(250.0, 250.0)
+            .with_orgien(true);
+
+        Self {
+            shapes: vec![linear, radial, conic, screen],
+        }
+    }
+}
+
+impl PluginGalar for Paints {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        let time = config.time();
+
+        self.shapes[0].transform.rotation = time * 0.8;
+        self.shapes[2].transform.rotation = -time;
+        self.shapes[3].transform.translation.y = 250.0 + (time * 1.5).sin() * 40.0;
+
+        for shape in &self.shapes {
+            draw_shape(config, shape);
+        }
+    }
+    fn name(&self) -> &str {
+        "Gradient Paints"
+    }
+}
@@ -0,0 +1,109 @@
+use galar::prelude::*;
+
+const WORLD: f32 = 800.0;
+
+fn main() -> Result<(), String> {
+    let mut galar = Galar::new("Render Target Example", 400, 400, 60, None)?
+        .with_camera(Camera2D::centered(400, 400).with_bounds(Vec2::ZERO, Vec2::splat(WORLD)));
+
+    println!("\nMueve la cámara con las flechas, 'ESC' para cerrar 😉\n");
+
+    galar.add_plugin(Minimap::new(8));
+
+    galar.run()
+}
+
+struct Minimap {
+    tiles: Vec<Shape>,
+    minimap: RenderTarget,
+    monitor: Shape,
+}
+
+impl Minimap {
+    fn new(cells: usize) -> Self {
+        let size = WORLD / cells as f32;
+        let mut tiles = Vec::with_capacity(cells * cells);
+        for idx in 0..(cells * cells) {
+            let col = idx % cells;
+            let row = idx / cells;
+            let t = idx as f32 / (cells * cells) as f32;
+            tiles.push(
+                Shape::quad((size * 0.9) as usize)
+                    .with_position(col as f32 * size, row as f32 * size)
+                    .with_color(Color::rainbow(t)),
+            );
+        }
+
+        // El minimapa ve el mundo completo con su propia cámara
+        let mut minimap = RenderTarget::new(100, 100)
+            .with_opaque(true)
+            .with_camera(Camera2D::new().with_zoom(100.0 / WORLD));
+
+        // Un "monitor" dentro del mundo que muestra el mismo target como Material
+        let monitor = Shape::quad(160)
+            .with_position(WORLD / 2.0 - 80.0, WORLD / 2.0 - 80.0)
+            .with_material(minimap.material());
+
+        Self {
+            tiles,
+            minimap,
+            monitor,
+        }
+    }
+}
+
+impl PluginGalar for Minimap {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        let speed = 200.0 * config.delta_time();
+        let mut delta = Vec2::ZERO;
+        if config.is_key_down(Key::Left) {
+            delta.x -= speed;
+        }
+        if config.is_key_down(Key::Right) {
+            delta.x += speed;
+        }
+        if config.is_key_down(Key::Up) {
+            delta.y -= speed;
+        }
+        if config.is_key_down(Key::Down) {
+            delta.y += speed;
+        }
+        config.camera.pan_world(delta);
+
+        // Región visible de la cámara principal, en coordenadas del mundo
+        let (width, height) = config.size();
+        let top_left = config.camera.screen_to_world(Vec2::ZERO);
+        let bottom_right = config.camera.screen_to_world(Vec2::new(width as f32, height as f32));
+
+        let tiles = &self.tiles;
+        self.minimap.clear(Color::BLACK.to_hex());
+        config.draw_to(&mut self.minimap, |config| {
+            for tile in tiles {
+                draw_shape(config, tile);
+            }
+
+            // Recuadro de lo que se ve en pantalla
+            let min = config.camera.world_to_screen(top_left).max(Vec2::ZERO);
+            let max = config.camera.world_to_screen(bottom_right).max(Vec2::ZERO);
+            let (x0, y0, x1, y1) = (min.x as usize, min.y as usize, max.x as usize, max.y as usize);
+            let white = Color::WHITE.to_hex();
+            config.draw_line(x0, y0, x1, y0, white);
+            config.draw_line(x1, y0, x1, y1, white);
+            config.draw_line(x1, y1, x0, y1, white);
+            config.draw_line(x0, y1, x0, y0, white);
+        });
+
+        for tile in &self.tiles {
+            draw_shape(config, tile);
+        }
+        draw_shape(config, &self.monitor);
+
+        self.minimap.blit(config, (width - 104) as isize, 4);
+    }
+    fn init(&mut self, config: &mut ConfigGalar) {
+        config.set_background(Color::BLACK.to_hex());
+    }
+    fn name(&self) -> &str {
+        "Minimap"
+    }
+}
@@ -0,0 +1,79 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    let mut galar = Galar::new("Noise Gallery Example", 400, 400, 60, None)?;
+
+    println!("\nPresiona 'R' para generar otra galería y 'ESC' para cerrar la ventana 😉\n");
+
+    galar.add_plugin(NoiseGallery::default());
+
+    galar.run()
+}
+
+#[derive(Default)]
+struct NoiseGallery {
+    tiles: Vec<Shape>,
+    was_down: bool,
+}
+
+impl NoiseGallery {
+    // Cuatro cuadrantes de 200x200, con semillas del generador de Galar
+    fn generate(&mut self, config: &mut ConfigGalar) {
+        let rng = config.rng();
+        let terrain = Gradient::from_stops(vec![
+            ColorStop::new(0.0, Color::NAVY),
+            ColorStop::new(0.45, Color::rgb(30, 110, 200)),
+            ColorStop::new(0.5, Color::rgb(230, 210, 150)),
+            ColorStop::new(0.6, Color::rgb(60, 150, 60)),
+            ColorStop::new(0.85, Color::rgb(110, 90, 70)),
+            ColorStop::new(1.0, Color::WHITE),
+        ]);
+
+        let fbm = Fractal::fbm(Perlin::new(rng.random())).with_octaves(6);
+        let ridged = Fractal::ridged(Simplex::new(rng.random())).with_octaves(5);
+        let cells = Worley::new(rng.random()).with_output(WorleyOutput::F2MinusF1);
+        let warped = DomainWarp::new(
+            Fractal::fbm(Simplex::new(rng.random())),
+            Fractal::fbm(Perlin::new(rng.random())).with_octaves(3),
+        )
+        .with_amplitude(1.5);
+
+        let textures = [
+            fbm.to_texture(200, 200, 0.015, &terrain),
+            ridged.to_texture(200, 200, 0.01, &Colormap::Magma.gradient(16)),
+            cells.to_texture(200, 200, 0.04, &Colormap::Viridis.gradient(16)),
+            warped.to_texture(200, 200, 0.01, &Colormap::Turbo.gradient(16)),
+        ];
+
+        self.tiles = textures
+            .into_iter()
+            .enumerate()
+            .map(|(index, texture)| {
+                let (x, y) = ((index % 2) as f32 * 200.0, (index / 2) as f32 * 200.0);
+                Shape::quad(200)
+                    .with_material(Material::from_texture(texture))
+                    .with_position(x, y)
+            })
+            .collect();
+    }
+}
+
+impl PluginGalar for NoiseGallery {
+    fn init(&mut self, config: &mut ConfigGalar) {
+        self.generate(config);
+    }
+    fn update(&mut self, config: &mut ConfigGalar) {
+        let down = config.is_key_down(Key::R);
+        if down && !self.was_down {
+            self.generate(config);
+        }
+        self.was_down = down;
+
+        for tile in &self.tiles {
+            draw_shape(config, tile);
+        }
+    }
+    fn name(&self) -> &str {
+        "Noise Gallery"
+    }
+}
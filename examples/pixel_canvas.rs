@@ -0,0 +1,54 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    let options = WindowOptions {
+        resize: true,
+        ..WindowOptions::default()
+    };
+
+    let mut galar = Galar::new("Pixel Canvas Example", 640, 480, 60, Some(options))?
+        .with_virtual_resolution(160, 120)
+        .with_resize_policy(ResizePolicy::IntegerScale);
+
+    println!("\nDibuja con el Mouse, 'C' limpia el lienzo, 'ESC' para cerrar 😉\n");
+
+    galar.add_plugin(PixelCanvas);
+
+    galar.run()
+}
+
+struct PixelCanvas;
+
+impl PluginGalar for PixelCanvas {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        if config.is_key_down(Key::C) {
+            clear_canvas(config);
+        }
+
+        // La posición llega ya en píxeles del lienzo lógico
+        if let Some((x, y)) = config.get_mouse_position(MouseMode::Discard) {
+            let (width, _) = config.size();
+            let color = Color::rainbow(x / width as f32).to_hex();
+            config.draw_pixel(x as usize, y as usize, color);
+        }
+    }
+    fn init(&mut self, config: &mut ConfigGalar) {
+        config.set_clean_pixels(false);
+        config.set_background(Color::NAVY.to_hex());
+        clear_canvas(config);
+    }
+    fn resize(&mut self, config: &mut ConfigGalar, width: usize, height: usize) {
+        let (logical_w, logical_h) = config.size();
+        println!("Ventana {width}x{height}, lienzo {logical_w}x{logical_h}");
+    }
+    fn name(&self) -> &str {
+        "Pixel Canvas"
+    }
+}
+
+fn clear_canvas(config: &mut ConfigGalar) {
+    let background = *config.background;
+    for index in config.iter_d1() {
+        config.explicit_draw(index, background);
+    }
+}
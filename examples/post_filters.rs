@@ -0,0 +1,78 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    let mut galar = Galar::new("Post-process Example", 400, 400, 60, None)?
+        .with_post_effect(GaussianBlur::new(2.0))
+        .with_post_effect(Sharpen::new(1.0))
+        .with_post_effect(EdgeDetect::new(1.0))
+        .with_post_effect(Emboss::new(1.0))
+        .with_post_effect(Convolution::new(
+            Kernel::new(5, 1, vec![1.0, 0.0, 0.0, 0.0, 1.0])?.normalized(),
+        ));
+
+    println!("\n'1'..'5' activan blur, sharpen, bordes, relieve y un núcleo propio, 'ESC' para cerrar 😉\n");
+
+    galar.add_plugin(PostFilters::new());
+
+    galar.run()
+}
+
+struct PostFilters {
+    shapes: Vec<Shape>,
+    keys: [(Key, bool); 5],
+}
+
+impl PostFilters {
+    fn new() -> Self {
+        let shapes = (0..12)
+            .map(|i| {
+                let t = i as f32 / 12.0;
+                draw_circle(0.0, 0.0, 30.0, 24, Color::rainbow(t))
+                    .new_shape()
+                    .with_position(200.0, 200.0)
+            })
+            .collect();
+
+        Self {
+            shapes,
+            keys: [Key::Key1, Key::Key2, Key::Key3, Key::Key4, Key::Key5].map(|key| (key, false)),
+        }
+    }
+}
+
+impl PluginGalar for PostFilters {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        // Cada tecla alterna su efecto al presionarla
+        for (index, (key, was_down)) in self.keys.iter_mut().enumerate() {
+            let down = config.is_key_down(*key);
+            if down && !*was_down {
+                let enabled = config.post.toggle(index);
+                println!("Efecto {}: {}", index + 1, if enabled { "on" } else { "off" });
+            }
+            *was_down = down;
+        }
+
+        // El blur respira con el tiempo
+        let time = config.time();
+        if let Some(blur) = config.post.get_mut::<GaussianBlur>(0) {
+            blur.sigma = 1.0 + time.sin().abs() * 3.0;
+        }
+
+        for (i, shape) in self.shapes.iter_mut().enumerate() {
+            let angle = time + i as f32 * std::f32::consts::TAU / 12.0;
+            shape.set_position(200.0 + angle.cos() * 120.0, 200.0 + angle.sin() * 120.0);
+            draw_shape(config, shape);
+        }
+    }
+    fn init(&mut self, config: &mut ConfigGalar) {
+        config.set_background(Color::BLACK.to_hex());
+
+        // Se empieza solo con el blur
+        for index in 1..config.post.len() {
+            config.post.set_enabled(index, false);
+        }
+    }
+    fn name(&self) -> &str {
+        "Post Filters"
+    }
+}
@@ -0,0 +1,72 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    // Opcional: cargo run --example retro_dither -- ruta/a/paleta.gpl
+    let palette = match std::env::args().nth(1) {
+        Some(path) => Palette::load(&path)?,
+        None => Palette::pico8(),
+    };
+
+    let mut galar = Galar::new("Dithering Example", 320, 240, 60, None)?
+        .with_virtual_resolution(160, 120)
+        .with_post_effect(Quantize::new(palette, Dither::Bayer(4)));
+
+    println!("\n'1'..'5' eligen el tramado, 'G' alterna la paleta de Game Boy, 'ESC' para cerrar 😉\n");
+
+    galar.add_plugin(RetroDither {
+        gameboy: false,
+        was_down: false,
+    });
+
+    galar.run()
+}
+
+struct RetroDither {
+    gameboy: bool,
+    was_down: bool,
+}
+
+impl PluginGalar for RetroDither {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        let dithers = [
+            (Key::Key1, Dither::None),
+            (Key::Key2, Dither::Bayer(4)),
+            (Key::Key3, Dither::FloydSteinberg),
+            (Key::Key4, Dither::Atkinson),
+            (Key::Key5, Dither::BlueNoise),
+        ];
+        let selected = dithers
+            .iter()
+            .find(|(key, _)| config.is_key_down(*key))
+            .map(|(_, dither)| *dither);
+        let down = config.is_key_down(Key::G);
+        let toggle = down && !self.was_down;
+        self.was_down = down;
+
+        if let Some(quantize) = config.post.get_mut::<Quantize>(0) {
+            if let Some(dither) = selected {
+                quantize.dither = dither;
+            }
+            if toggle {
+                self.gameboy = !self.gameboy;
+                let palette = if self.gameboy { Palette::gameboy() } else { Palette::pico8() };
+                quantize.set_palette(palette);
+            }
+        }
+
+        // Degradado suave que obliga a tramar
+        let (width, height) = config.size();
+        let time = config.time();
+        for y in 0..height {
+            for x in 0..width {
+                let t = x as f32 / width as f32;
+                let light = y as f32 / height as f32;
+                let hue = (t * 360.0 + time * 30.0) % 360.0;
+                config.draw_pixel(x, y, Color::hsl(hue, 0.7, light).to_hex());
+            }
+        }
+    }
+    fn name(&self) -> &str {
+        "Retro Dither"
+    }
+}
@@ -0,0 +1,55 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    let mut galar = Galar::new("Scene Graph Example", 400, 400, 60, None)?;
+
+    println!("\nPresiona 'ESC' para cerrar la ventana 😉\n");
+
+    galar.add_plugin(Orbits::new());
+
+    galar.run()
+}
+
+struct Orbits {
+    scene: SceneNode,
+}
+
+impl Orbits {
+    fn new() -> Self {
+        // La luna gira alrededor de la tierra, que gira alrededor del sol
+        let moon = SceneNode::new("moon")
+            .with_position(30.0, 0.0)
+            .with_shape(draw_circle(0.0, 0.0, 5.0, 12, Color::SILVER).new_shape())
+            .with_layer(2);
+
+        let earth_orbit = SceneNode::new("earth_orbit")
+            .with_shape(draw_circle(0.0, 0.0, 12.0, 20, Color::BLUE).new_shape())
+            .with_position(120.0, 0.0)
+            .with_layer(1)
+            .with_child(moon);
+
+        let sun = SceneNode::new("sun")
+            .with_position(200.0, 200.0)
+            .with_shape(draw_circle(0.0, 0.0, 30.0, 32, Color::GOLD).new_shape())
+            .with_child(earth_orbit);
+
+        Self { scene: sun }
+    }
+}
+
+impl PluginGalar for Orbits {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        self.scene.rotate(0.5);
+        if let Some(earth) = self.scene.find_mut("earth_orbit") {
+            earth.rotate(3.0);
+        }
+
+        self.scene.update(config);
+    }
+    fn init(&mut self, config: &mut ConfigGalar) {
+        config.set_background(Color::BLACK.to_hex());
+    }
+    fn name(&self) -> &str {
+        "Scene Orbits"
+    }
+}
@@ -0,0 +1,59 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    let mut galar = Galar::new("Screen Effects Example", 400, 400, 60, None)?
+        .with_post_effect(Bloom::new(0.6, 1.2).with_sigma(3.0))
+        .with_post_effect(ChromaticAberration::new(2.0))
+        .with_post_effect(Vignette::new(0.7))
+        .with_post_effect(FilmGrain::new(0.06))
+        .with_post_effect(Crt::new());
+
+    println!("\n'1'..'5' alternan bloom, aberración, viñeta, grano y CRT, 'ESC' para cerrar 😉\n");
+
+    galar.add_plugin(ScreenEffects::new());
+
+    galar.run()
+}
+
+struct ScreenEffects {
+    keys: [(Key, bool); 5],
+}
+
+impl ScreenEffects {
+    fn new() -> Self {
+        Self {
+            keys: [Key::Key1, Key::Key2, Key::Key3, Key::Key4, Key::Key5].map(|key| (key, false)),
+        }
+    }
+}
+
+impl PluginGalar for ScreenEffects {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        for (index, (key, was_down)) in self.keys.iter_mut().enumerate() {
+            let down = config.is_key_down(*key);
+            if down && !*was_down {
+                let enabled = config.post.toggle(index);
+                println!("Efecto {}: {}", index + 1, if enabled { "on" } else { "off" });
+            }
+            *was_down = down;
+        }
+
+        // Curvas de Lissajous brillantes sobre fondo oscuro
+        let time = config.time();
+        let (width, height) = config.size();
+        let (cx, cy) = (width as f32 / 2.0, height as f32 / 2.0);
+        for i in 0..600 {
+            let t = i as f32 / 600.0 * std::f32::consts::TAU;
+            let x = cx + (3.0 * t + time).sin() * cx * 0.7;
+            let y = cy + (2.0 * t).sin() * cy * 0.7;
+            let color = Color::rainbow((i as f32 / 600.0 + time * 0.1).fract());
+            config.draw_circle(x as usize, y as usize, 2, color.to_hex());
+        }
+    }
+    fn init(&mut self, config: &mut ConfigGalar) {
+        config.set_background(Color::rgb(10, 10, 20).to_hex());
+    }
+    fn name(&self) -> &str {
+        "Screen Effects"
+    }
+}
@@ -0,0 +1,100 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    let mut galar = Galar::new("SDF Scene Example", 400, 400, 60, None)?;
+
+    println!("\nPresiona 'ESPACIO' para lanzar otra pelota y 'ESC' para cerrar la ventana 😉\n");
+
+    let style = SdfStyle::fill(Color::rgb(40, 60, 90)).with_outline(Color::rgb(120, 200, 255), 2.0);
+    galar.add_plugin(SdfPlugin::new(level(), style));
+    galar.add_plugin(Ball::new(level()));
+
+    galar.run()
+}
+
+// Suelo con un hueco redondeado, rampa, estrellas repetidas y un arco como techo
+fn level() -> Box<dyn Sdf> {
+    let floor = SdfBox::from_rect(0.0, 360.0, 400.0, 40.0)
+        .smooth_subtraction(SdfCircle::new(Vec2::new(200.0, 360.0), 40.0), 12.0);
+    let ramp = SdfPolygon::new(vec![
+        Vec2::new(0.0, 360.0),
+        Vec2::new(0.0, 260.0),
+        Vec2::new(120.0, 360.0),
+    ]);
+    let pegs = SdfStar::new(Vec2::ZERO, 14.0, 5)
+        .repeat_limited(Vec2::new(80.0, 0.0), Vec2::new(1.0, 0.0))
+        .translated(Vec2::new(260.0, 200.0));
+    let roof = SdfArc::new(Vec2::new(200.0, 170.0), 150.0, 0.9, 6.0);
+    let walls = SdfBox::new(Vec2::new(200.0, 200.0), Vec2::new(200.0, 200.0)).onion(8.0);
+
+    Box::new(
+        floor
+            .union(ramp)
+            .union(pegs)
+            .union(roof)
+            .union(walls)
+            .rounded(2.0),
+    )
+}
+
+struct Ball {
+    level: Box<dyn Sdf>,
+    position: Vec2,
+    velocity: Vec2,
+    radius: f32,
+    was_down: bool,
+}
+
+impl Ball {
+    fn new(level: Box<dyn Sdf>) -> Self {
+        Self {
+            level,
+            position: Vec2::new(60.0, 60.0),
+            velocity: Vec2::new(120.0, 0.0),
+            radius: 10.0,
+            was_down: false,
+        }
+    }
+}
+
+impl PluginGalar for Ball {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        let down = config.is_key_down(Key::Space);
+        if down && !self.was_down {
+            let rng = config.rng();
+            self.position = Vec2::new(rng.random_range(60.0..340.0), 60.0);
+            self.velocity = Vec2::new(rng.random_range(-200.0..200.0), 0.0);
+        }
+        self.was_down = down;
+
+        let dt = config.delta_time().min(1.0 / 30.0);
+        self.velocity.y += 600.0 * dt;
+        self.position += self.velocity * dt;
+
+        // Se saca la pelota de la pared y se refleja la velocidad sobre la normal
+        if let Some(push) = self.level.resolve_circle(self.position, self.radius) {
+            self.position += push;
+            let normal = push.normalize_or_zero();
+            let into = self.velocity.dot(normal);
+            if into < 0.0 {
+                self.velocity -= normal * into * 1.7;
+            }
+        }
+
+        draw_sdf(
+            config,
+            &SdfCircle::new(self.position, self.radius),
+            &SdfStyle::fill(Color::rgb(255, 170, 40)),
+        );
+
+        // Sombra proyectada hacia abajo con un rayo
+        if let Some(hit) = self.level.raycast(self.position, Vec2::Y, 400.0) {
+            let ground = self.position + Vec2::Y * hit;
+            let shadow = SdfSegment::new(ground - Vec2::X * 8.0, ground + Vec2::X * 8.0, 1.5);
+            draw_sdf(config, &shadow, &SdfStyle::fill(Color::rgba(0, 0, 0, 120)));
+        }
+    }
+    fn name(&self) -> &str {
+        "Ball"
+    }
+}
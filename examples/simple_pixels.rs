@@ -29,17 +29,13 @@ impl PluginGalar for SimpleGeometry {
         for _ in 0..10 {
             
             // dibuja pixeles segun las coordenadas
-            config.draw_pixel(
-                random_range(0..width - 1),
-                random_range(0..height - 1),
-                Color::random().to_hex(),
-            );
+            let x = config.rng().random_range(0..width - 1);
+            let y = config.rng().random_range(0..height - 1);
+            config.draw_pixel(x, y, Color::random().to_hex());
 
             // dibuja pixeles segun el indice del frame buffer
-            config.explicit_draw(
-                random_range(0..(width - 1) * (height - 1)),
-                Color::random().to_hex(),
-            );
+            let index = config.rng().random_range(0..(width - 1) * (height - 1));
+            config.explicit_draw(index, Color::random().to_hex());
             
         }
     }
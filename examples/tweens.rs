@@ -0,0 +1,79 @@
+use galar::prelude::*;
+
+fn main() -> Result<(), String> {
+    let mut galar = Galar::new("Tween Example", 400, 400, 60, None)?;
+
+    println!("\nPresiona 'ESC' para cerrar la ventana 😉\n");
+
+    galar.add_plugin(Tweens::new());
+
+    galar.run()
+}
+
+struct Tweens {
+    shape: Shape,
+    animator: Animator<Shape>,
+}
+
+impl Tweens {
+    fn new() -> Self {
+        let mut animator = Animator::new();
+
+        // Cae rebotando, gira y luego cambia de color
+        animator.play(
+            "intro",
+            Sequence::new()
+                .then(
+                    Parallel::new()
+                        .with(
+                            Tween::new(Vec2::new(175.0, -50.0), Vec2::new(175.0, 300.0), 1.5)
+                                .with_easing(Easing::BounceOut)
+                                .bind(|shape: &mut Shape, v| shape.transform.translation = v),
+                        )
+                        .with(
+                            Tween::new(0.0, std::f32::consts::TAU, 1.5)
+                                .with_easing(Easing::CubicInOut)
+                                .bind(|shape: &mut Shape, v| shape.transform.rotation = v),
+                        ),
+                )
+                .then(
+                    Tween::new(Color::ORANGE, Color::TEAL, 0.75)
+                        .bind(|shape: &mut Shape, c| shape.set_color(c))
+                        .on_complete(|_| println!("🟢 Intro terminada")),
+                ),
+        );
+
+        // Respira indefinidamente con keyframes en ida y vuelta
+        animator.play(
+            "breath",
+            Track::new()
+                .key(0.0, Vec2::ONE)
+                .key_eased(1.0, Vec2::splat(1.5), Easing::SineInOut)
+                .with_mode(LoopMode::PingPong)
+                .bind(|shape: &mut Shape, v| shape.transform.scale = v),
+        );
+
+        Self {
+            shape: Shape::quad(50).with_color(Color::ORANGE).with_orgien(true),
+            animator,
+        }
+    }
+}
+
+impl PluginGalar for Tweens {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        self.animator.update(&mut self.shape, config.delta_time());
+
+        for event in self.animator.drain_events() {
+            println!("Evento: {event:?}");
+        }
+
+        draw_shape(config, &self.shape);
+    }
+    fn init(&mut self, config: &mut ConfigGalar) {
+        config.set_background(Color::BLACK.to_hex());
+    }
+    fn name(&self) -> &str {
+        "Tweens"
+    }
+}
@@ -14,14 +14,14 @@ fn main() -> Result<(), String> {
 
 #[derive(Default)]
 struct Voronoi {
-    points: Vec<(usize, usize)>,
+    points: KdTree<usize>,
     n_points: usize,
 }
 
 impl Voronoi {
     fn new(n_points: usize) -> Self {
         Self {
-            points: Vec::new(),
+            points: KdTree::new(),
             n_points,
         }
     }
@@ -55,35 +55,18 @@ impl PluginGalar for Voronoi {
 
             // let base_color = Color::rgb(r, g, b).to_hex();
 
-            let closest = self
+            // El árbol k-d evita comparar cada píxel con todas las semillas
+            let Some((_, &closest)) = self
                 .points
-                .iter()
-                .enumerate()
-                .min_by_key(|(_, point)| {
-                    Float(proximidad(
-                        x as f32,
-                        y as f32,
-                        point.0 as f32,
-                        point.1 as f32,
-                    ))
-                })
-                .unwrap()
-                .0;
+                .nearest(Vec2::new(x as f32, y as f32))
+                .and_then(|id| self.points.get(id))
+            else {
+                continue;
+            };
 
-            let base_color = match closest % 10 {
-                0 => Color::rgb(255, 0, 0),
-                1 => Color::rgb(0, 255, 0),
-                2 => Color::rgb(0, 0, 255),
-                3 => Color::rgb(255, 0, 255),
-                4 => Color::rgb(255, 255, 0),
-                5 => Color::rgb(0, 255, 255),
-                6 => Color::rgb(89, 0, 255),
-                7 => Color::rgb(0, 0, 0),
-                8 => Color::rgb(255, 255, 255),
-                9 => Color::rgb(255, 166, 0),
-                _ => Color::random(),
-            }
-            .to_hex();
+            // Cada celda toma un color del mapa según su índice
+            let t = closest as f32 / self.n_points.saturating_sub(1).max(1) as f32;
+            let base_color = Colormap::Viridis.sample(t).to_hex();
 
             config.draw_pixel(x, y, base_color);
         }
@@ -93,11 +76,14 @@ impl PluginGalar for Voronoi {
 
         if self.points.is_empty() {
             let (width, height) = config.size();
-            for _ in 0..self.n_points {
-                let x = random_range(0..width);
-                let y = random_range(0..height);
-                self.points.push((x, y));
+            let mut seeds = Vec::with_capacity(self.n_points);
+            for index in 0..self.n_points {
+                // Con la misma semilla (`--seed`) se repite el mismo diagrama
+                let x = config.rng().random_range(0..width);
+                let y = config.rng().random_range(0..height);
+                seeds.push((Vec2::new(x as f32, y as f32), index));
             }
+            self.points = KdTree::from_points(seeds);
         }
     }
     fn name(&self) -> &str {
@@ -0,0 +1,649 @@
+use std::f32::consts::PI;
+
+use glam::Vec2;
+
+use super::{colores::Color, transform::Transform};
+
+/// Curvas de aceleración clásicas (Penner). Reciben y devuelven t en 0.0 .. 1.0,
+/// aunque `Back` y `Elastic` se salen un poco del rango a propósito.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    /// Saltos discretos, útil para animaciones tipo reloj
+    Steps(u32),
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+impl Easing {
+    pub fn ease(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        const C1: f32 = 1.70158;
+        const C2: f32 = C1 * 1.525;
+        const C3: f32 = C1 + 1.0;
+        const C4: f32 = (2.0 * PI) / 3.0;
+        const C5: f32 = (2.0 * PI) / 4.5;
+
+        match *self {
+            Easing::Linear => t,
+            Easing::Steps(steps) => {
+                let steps = steps.max(1) as f32;
+                (t * steps).floor().min(steps) / steps
+            }
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::QuadInOut => in_out(t, |t| t * t),
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Easing::CubicInOut => in_out(t, |t| t * t * t),
+            Easing::QuartIn => t.powi(4),
+            Easing::QuartOut => 1.0 - (1.0 - t).powi(4),
+            Easing::QuartInOut => in_out(t, |t| t.powi(4)),
+            Easing::QuintIn => t.powi(5),
+            Easing::QuintOut => 1.0 - (1.0 - t).powi(5),
+            Easing::QuintInOut => in_out(t, |t| t.powi(5)),
+            Easing::SineIn => 1.0 - (t * PI / 2.0).cos(),
+            Easing::SineOut => (t * PI / 2.0).sin(),
+            Easing::SineInOut => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::ExpoIn => expo_in(t),
+            Easing::ExpoOut => 1.0 - expo_in(1.0 - t),
+            Easing::ExpoInOut => in_out(t, expo_in),
+            Easing::CircIn => 1.0 - (1.0 - t * t).sqrt(),
+            Easing::CircOut => (1.0 - (t - 1.0) * (t - 1.0)).sqrt(),
+            Easing::CircInOut => in_out(t, |t| 1.0 - (1.0 - t * t).sqrt()),
+            Easing::BackIn => C3 * t * t * t - C1 * t * t,
+            Easing::BackOut => 1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2),
+            Easing::BackInOut => in_out(t, |t| (C2 + 1.0) * t * t * t - C2 * t * t),
+            Easing::ElasticIn => elastic_in(t, C4),
+            Easing::ElasticOut => 1.0 - elastic_in(1.0 - t, C4),
+            Easing::ElasticInOut => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else if t < 0.5 {
+                    -(2f32.powf(20.0 * t - 10.0) * ((20.0 * t - 11.125) * C5).sin()) / 2.0
+                } else {
+                    (2f32.powf(-20.0 * t + 10.0) * ((20.0 * t - 11.125) * C5).sin()) / 2.0 + 1.0
+                }
+            }
+            Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+        }
+    }
+}
+
+/// Construye la versión InOut a partir de la curva In
+#[inline(always)]
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(2.0 * t) / 2.0
+    } else {
+        1.0 - ease_in(2.0 - 2.0 * t) / 2.0
+    }
+}
+
+fn expo_in(t: f32) -> f32 {
+    if t == 0.0 { 0.0 } else { 2f32.powf(10.0 * t - 10.0) }
+}
+
+fn elastic_in(t: f32, c4: f32) -> f32 {
+    if t == 0.0 || t == 1.0 {
+        return t;
+    }
+    -(2f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * c4).sin()
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+
+    if t < 1.0 / D1 {
+        N1 * t * t
+    } else if t < 2.0 / D1 {
+        let t = t - 1.5 / D1;
+        N1 * t * t + 0.75
+    } else if t < 2.5 / D1 {
+        let t = t - 2.25 / D1;
+        N1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D1;
+        N1 * t * t + 0.984375
+    }
+}
+
+/// Valores que se pueden interpolar
+pub trait Tweenable: Clone {
+    fn tween(from: &Self, to: &Self, t: f32) -> Self;
+}
+
+impl Tweenable for f32 {
+    fn tween(from: &Self, to: &Self, t: f32) -> Self {
+        from + (to - from) * t
+    }
+}
+
+impl Tweenable for Vec2 {
+    fn tween(from: &Self, to: &Self, t: f32) -> Self {
+        from.lerp(*to, t)
+    }
+}
+
+impl Tweenable for Color {
+    fn tween(from: &Self, to: &Self, t: f32) -> Self {
+        Color::lerp(*from, *to, t)
+    }
+}
+
+impl Tweenable for Transform {
+    fn tween(from: &Self, to: &Self, t: f32) -> Self {
+        Transform {
+            translation: from.translation.lerp(to.translation, t),
+            rotation: f32::tween(&from.rotation, &to.rotation, t),
+            scale: from.scale.lerp(to.scale, t),
+            skew: from.skew.lerp(to.skew, t),
+            pivot: from.pivot.lerp(to.pivot, t),
+        }
+    }
+}
+
+/// Cómo se repite una animación al llegar al final
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LoopMode {
+    /// Se reproduce una vez y se detiene en el final
+    #[default]
+    Once,
+    /// Vuelve a empezar desde el inicio
+    Loop,
+    /// Va y vuelve (ida y vuelta) indefinidamente
+    PingPong,
+}
+
+/// Reloj interno compartido por tweens y pistas de keyframes
+#[derive(Debug, Clone, Copy)]
+struct Clock {
+    duration: f32,
+    elapsed: f32,
+    mode: LoopMode,
+}
+
+impl Clock {
+    fn new(duration: f32) -> Self {
+        Self {
+            duration: duration.max(0.0),
+            elapsed: 0.0,
+            mode: LoopMode::Once,
+        }
+    }
+
+    /// Avanza y devuelve el tiempo sobrante después de terminar (solo en `Once`)
+    fn advance(&mut self, dt: f32) -> f32 {
+        self.elapsed += dt.max(0.0);
+        match self.mode {
+            LoopMode::Once => (self.elapsed - self.duration).max(0.0),
+            // Se envuelve para no perder precisión tras horas de ejecución
+            LoopMode::Loop if self.duration > 0.0 => {
+                self.elapsed %= self.duration;
+                0.0
+            }
+            LoopMode::PingPong if self.duration > 0.0 => {
+                self.elapsed %= 2.0 * self.duration;
+                0.0
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Progreso lineal de 0.0 a 1.0 en la dirección actual
+    fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        match self.mode {
+            LoopMode::Once => (self.elapsed / self.duration).min(1.0),
+            LoopMode::Loop => self.elapsed / self.duration,
+            LoopMode::PingPong => {
+                let cycle = self.elapsed / self.duration;
+                if cycle <= 1.0 { cycle } else { 2.0 - cycle }
+            }
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.mode == LoopMode::Once && self.elapsed >= self.duration
+    }
+}
+
+/// Fuente de valores en el tiempo: `Tween` y `Track`
+pub trait Sampler {
+    type Value: Tweenable;
+
+    /// Avanza `dt` segundos, devuelve el tiempo sobrante si terminó
+    fn advance(&mut self, dt: f32) -> f32;
+    fn value(&self) -> Self::Value;
+    fn is_finished(&self) -> bool;
+    fn reset(&mut self);
+
+    /// Asocia el valor a una propiedad de `T` mediante un "lente"
+    fn bind<T, F>(self, apply: F) -> Bound<T, Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut T, Self::Value),
+    {
+        Bound {
+            source: self,
+            apply,
+            on_complete: None,
+            completed: false,
+        }
+    }
+}
+
+/// Interpolación de `from` a `to` durante `duration` segundos
+#[derive(Debug, Clone)]
+pub struct Tween<V: Tweenable> {
+    pub from: V,
+    pub to: V,
+    pub easing: Easing,
+    clock: Clock,
+}
+
+impl<V: Tweenable> Tween<V> {
+    pub fn new(from: V, to: V, duration: f32) -> Self {
+        Self {
+            from,
+            to,
+            easing: Easing::Linear,
+            clock: Clock::new(duration),
+        }
+    }
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+    pub fn with_mode(mut self, mode: LoopMode) -> Self {
+        self.clock.mode = mode;
+        self
+    }
+    /// Progreso lineal (sin easing) de 0.0 a 1.0
+    pub fn progress(&self) -> f32 {
+        self.clock.progress()
+    }
+    pub fn duration(&self) -> f32 {
+        self.clock.duration
+    }
+}
+
+impl<V: Tweenable> Sampler for Tween<V> {
+    type Value = V;
+
+    fn advance(&mut self, dt: f32) -> f32 {
+        self.clock.advance(dt)
+    }
+    fn value(&self) -> V {
+        V::tween(&self.from, &self.to, self.easing.ease(self.clock.progress()))
+    }
+    fn is_finished(&self) -> bool {
+        self.clock.is_finished()
+    }
+    fn reset(&mut self) {
+        self.clock.elapsed = 0.0;
+    }
+}
+
+/// Un valor en un instante de la pista. `easing` define la curva del tramo que llega a él.
+#[derive(Debug, Clone)]
+pub struct Keyframe<V: Tweenable> {
+    pub time: f32,
+    pub value: V,
+    pub easing: Easing,
+}
+
+/// Pista de keyframes ordenados por tiempo
+#[derive(Debug, Clone)]
+pub struct Track<V: Tweenable> {
+    keys: Vec<Keyframe<V>>,
+    clock: Clock,
+}
+
+impl<V: Tweenable> Default for Track<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Tweenable> Track<V> {
+    pub fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            clock: Clock::new(0.0),
+        }
+    }
+    /// Añade un keyframe con interpolación lineal
+    pub fn key(self, time: f32, value: V) -> Self {
+        self.key_eased(time, value, Easing::Linear)
+    }
+    pub fn key_eased(mut self, time: f32, value: V, easing: Easing) -> Self {
+        let index = self.keys.partition_point(|key| key.time <= time);
+        self.keys.insert(index, Keyframe { time, value, easing });
+        self.clock.duration = self.keys.last().map_or(0.0, |key| key.time);
+        self
+    }
+    pub fn with_mode(mut self, mode: LoopMode) -> Self {
+        self.clock.mode = mode;
+        self
+    }
+    pub fn keys(&self) -> &[Keyframe<V>] {
+        &self.keys
+    }
+    pub fn duration(&self) -> f32 {
+        self.clock.duration
+    }
+
+    /// Valor de la pista en el instante `time` (segundos)
+    pub fn sample_at(&self, time: f32) -> Option<V> {
+        let first = self.keys.first()?;
+        if time <= first.time {
+            return Some(first.value.clone());
+        }
+
+        let next = self.keys.partition_point(|key| key.time <= time);
+        if next >= self.keys.len() {
+            return self.keys.last().map(|key| key.value.clone());
+        }
+
+        let a = &self.keys[next - 1];
+        let b = &self.keys[next];
+        let span = b.time - a.time;
+        let t = if span > 0.0 { (time - a.time) / span } else { 1.0 };
+
+        Some(V::tween(&a.value, &b.value, b.easing.ease(t)))
+    }
+}
+
+impl<V: Tweenable + Default> Sampler for Track<V> {
+    type Value = V;
+
+    fn advance(&mut self, dt: f32) -> f32 {
+        self.clock.advance(dt)
+    }
+    fn value(&self) -> V {
+        self.sample_at(self.clock.progress() * self.clock.duration)
+            .unwrap_or_default()
+    }
+    fn is_finished(&self) -> bool {
+        self.clock.is_finished()
+    }
+    fn reset(&mut self) {
+        self.clock.elapsed = 0.0;
+    }
+}
+
+/// Animación que modifica un objetivo de tipo `T`
+pub trait Animation<T> {
+    /// Avanza `dt` segundos aplicando el resultado a `target`.
+    /// Devuelve el tiempo sobrante si la animación terminó en este paso.
+    fn update(&mut self, target: &mut T, dt: f32) -> f32;
+    fn is_finished(&self) -> bool;
+    fn reset(&mut self);
+}
+
+/// Callback que recibe el objetivo cuando una animación termina
+pub type CompleteCallback<T> = Box<dyn FnMut(&mut T)>;
+
+/// Un `Sampler` aplicado a una propiedad de `T`, con aviso opcional al terminar
+pub struct Bound<T, S: Sampler, F: FnMut(&mut T, S::Value)> {
+    source: S,
+    apply: F,
+    on_complete: Option<CompleteCallback<T>>,
+    completed: bool,
+}
+
+impl<T, S: Sampler, F: FnMut(&mut T, S::Value)> Bound<T, S, F> {
+    /// Se llama una vez cuando la animación termina
+    pub fn on_complete(mut self, callback: impl FnMut(&mut T) + 'static) -> Self {
+        self.on_complete = Some(Box::new(callback));
+        self
+    }
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+}
+
+impl<T, S: Sampler, F: FnMut(&mut T, S::Value)> Animation<T> for Bound<T, S, F> {
+    fn update(&mut self, target: &mut T, dt: f32) -> f32 {
+        if self.completed {
+            return dt;
+        }
+        let leftover = self.source.advance(dt);
+        (self.apply)(target, self.source.value());
+
+        if self.source.is_finished() {
+            self.completed = true;
+            if let Some(callback) = self.on_complete.as_mut() {
+                callback(target);
+            }
+        }
+        leftover
+    }
+    fn is_finished(&self) -> bool {
+        self.completed
+    }
+    fn reset(&mut self) {
+        self.source.reset();
+        self.completed = false;
+    }
+}
+
+/// Reproduce animaciones una tras otra; el tiempo sobrante pasa a la siguiente
+pub struct Sequence<T> {
+    items: Vec<Box<dyn Animation<T>>>,
+    current: usize,
+}
+
+impl<T> Default for Sequence<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Sequence<T> {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            current: 0,
+        }
+    }
+    pub fn then(mut self, animation: impl Animation<T> + 'static) -> Self {
+        self.items.push(Box::new(animation));
+        self
+    }
+}
+
+impl<T> Animation<T> for Sequence<T> {
+    fn update(&mut self, target: &mut T, dt: f32) -> f32 {
+        let mut remaining = dt;
+        while let Some(item) = self.items.get_mut(self.current) {
+            remaining = item.update(target, remaining);
+            if !item.is_finished() {
+                return 0.0;
+            }
+            self.current += 1;
+            if remaining <= 0.0 {
+                break;
+            }
+        }
+        if self.is_finished() { remaining } else { 0.0 }
+    }
+    fn is_finished(&self) -> bool {
+        self.current >= self.items.len()
+    }
+    fn reset(&mut self) {
+        self.current = 0;
+        for item in self.items.iter_mut() {
+            item.reset();
+        }
+    }
+}
+
+/// Reproduce animaciones a la vez; termina cuando terminan todas
+pub struct Parallel<T> {
+    items: Vec<Box<dyn Animation<T>>>,
+}
+
+impl<T> Default for Parallel<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Parallel<T> {
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+    pub fn with(mut self, animation: impl Animation<T> + 'static) -> Self {
+        self.items.push(Box::new(animation));
+        self
+    }
+}
+
+impl<T> Animation<T> for Parallel<T> {
+    fn update(&mut self, target: &mut T, dt: f32) -> f32 {
+        let mut leftover = dt;
+        for item in self.items.iter_mut() {
+            if item.is_finished() {
+                continue;
+            }
+            leftover = leftover.min(item.update(target, dt));
+        }
+        if self.is_finished() { leftover } else { 0.0 }
+    }
+    fn is_finished(&self) -> bool {
+        self.items.iter().all(|item| item.is_finished())
+    }
+    fn reset(&mut self) {
+        for item in self.items.iter_mut() {
+            item.reset();
+        }
+    }
+}
+
+/// Evento emitido por `Animator`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnimationEvent {
+    /// La animación con esa etiqueta terminó
+    Finished(String),
+}
+
+/// Reproduce varias animaciones etiquetadas sobre el mismo objetivo,
+/// se alimenta con `config.delta_time()` en cada `update`.
+pub struct Animator<T> {
+    animations: Vec<(String, Box<dyn Animation<T>>)>,
+    events: Vec<AnimationEvent>,
+}
+
+impl<T> Default for Animator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Animator<T> {
+    pub fn new() -> Self {
+        Self {
+            animations: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Añade una animación; si ya existía una con la misma etiqueta se reemplaza
+    pub fn play(&mut self, label: &str, animation: impl Animation<T> + 'static) {
+        self.stop(label);
+        self.animations.push((label.to_string(), Box::new(animation)));
+    }
+
+    pub fn stop(&mut self, label: &str) {
+        self.animations.retain(|(name, _)| name != label);
+    }
+
+    pub fn is_playing(&self, label: &str) -> bool {
+        self.animations.iter().any(|(name, _)| name == label)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.animations.is_empty()
+    }
+
+    /// Avanza todas las animaciones; las terminadas se quitan y generan un evento
+    pub fn update(&mut self, target: &mut T, dt: f32) {
+        for (_, animation) in self.animations.iter_mut() {
+            animation.update(target, dt);
+        }
+
+        let events = &mut self.events;
+        self.animations.retain(|(label, animation)| {
+            let finished = animation.is_finished();
+            if finished {
+                events.push(AnimationEvent::Finished(label.clone()));
+            }
+            !finished
+        });
+    }
+
+    /// Eventos acumulados desde la última llamada
+    pub fn drain_events(&mut self) -> impl Iterator<Item = AnimationEvent> + '_ {
+        self.events.drain(..)
+    }
+}
+
+/// Lentes listos para animar un `Transform`
+pub mod lens {
+    use glam::Vec2;
+
+    use crate::transform::Transform;
+
+    pub fn translation(transform: &mut Transform, value: Vec2) {
+        transform.translation = value;
+    }
+    pub fn rotation(transform: &mut Transform, value: f32) {
+        transform.rotation = value;
+    }
+    pub fn scale(transform: &mut Transform, value: Vec2) {
+        transform.scale = value;
+    }
+    pub fn skew(transform: &mut Transform, value: Vec2) {
+        transform.skew = value;
+    }
+    pub fn transform(transform: &mut Transform, value: Transform) {
+        *transform = value;
+    }
+}
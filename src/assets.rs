@@ -0,0 +1,137 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    time::SystemTime,
+};
+
+use super::texture::{Texture, TextureHandle};
+
+/// Almacén de texturas: cada ruta se decodifica una sola vez
+/// y se reparte como `TextureHandle` (clonar un handle no copia píxeles).
+#[derive(Debug, Default)]
+pub struct Assets {
+    textures: HashMap<PathBuf, TextureHandle>,
+    modified: HashMap<PathBuf, SystemTime>,
+}
+
+/// Resultado de revisar los archivos en disco
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetEvent {
+    /// El archivo cambió y la textura se recargó
+    Reloaded(PathBuf),
+    /// El archivo cambió pero no se pudo decodificar; se conserva la versión anterior
+    Failed(PathBuf, String),
+}
+
+impl Assets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Almacén compartido por `Material::load_texture` y `Shape::with_texture`
+    pub fn global() -> &'static Mutex<Assets> {
+        static GLOBAL: OnceLock<Mutex<Assets>> = OnceLock::new();
+        GLOBAL.get_or_init(|| Mutex::new(Assets::new()))
+    }
+
+    /// Devuelve la textura de la caché o la carga del disco la primera vez
+    pub fn load_texture(&mut self, path: &str) -> Result<TextureHandle, String> {
+        let key = Self::key(path);
+        if let Some(handle) = self.textures.get(&key) {
+            return Ok(handle.clone());
+        }
+
+        let handle = TextureHandle::new(Texture::load(path)?);
+        if let Some(time) = modified_time(&key) {
+            self.modified.insert(key.clone(), time);
+        }
+        self.textures.insert(key, handle.clone());
+        Ok(handle)
+    }
+
+    /// Revisa la fecha de modificación de los archivos cargados y recarga los que cambiaron.
+    /// Los errores no interrumpen nada: la textura anterior sigue en uso.
+    pub fn poll_changes(&mut self) -> Vec<AssetEvent> {
+        let mut events = Vec::new();
+
+        for (path, last) in self.modified.iter_mut() {
+            let Some(time) = modified_time(path) else {
+                continue;
+            };
+            if time == *last {
+                continue;
+            }
+            // Se guarda aunque falle: si el archivo estaba a medio escribir, la próxima
+            // escritura cambia la fecha y se reintenta.
+            *last = time;
+
+            let Some(handle) = self.textures.get(path) else {
+                continue;
+            };
+            match Texture::load(&path.to_string_lossy()) {
+                Ok(texture) => {
+                    handle.replace(texture);
+                    events.push(AssetEvent::Reloaded(path.clone()));
+                }
+                Err(error) => events.push(AssetEvent::Failed(path.clone(), error)),
+            }
+        }
+
+        events
+    }
+
+    /// Registra una textura generada en memoria bajo un nombre
+    pub fn insert(&mut self, name: &str, texture: Texture) -> TextureHandle {
+        let handle = TextureHandle::new(texture);
+        self.textures.insert(PathBuf::from(name), handle.clone());
+        handle
+    }
+
+    pub fn get(&self, path: &str) -> Option<TextureHandle> {
+        self.textures.get(&Self::key(path)).cloned()
+    }
+
+    pub fn contains(&self, path: &str) -> bool {
+        self.textures.contains_key(&Self::key(path))
+    }
+
+    pub fn remove(&mut self, path: &str) -> Option<TextureHandle> {
+        let key = Self::key(path);
+        self.modified.remove(&key);
+        self.textures.remove(&key)
+    }
+
+    /// Libera las texturas que solo siguen vivas dentro de la caché
+    pub fn unload_unused(&mut self) -> usize {
+        let before = self.textures.len();
+        self.textures.retain(|_, handle| handle.ref_count() > 1);
+        let textures = &self.textures;
+        self.modified.retain(|path, _| textures.contains_key(path));
+        before - self.textures.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.textures.clear();
+        self.modified.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.textures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.textures.is_empty()
+    }
+
+    /// Rutas distintas que apuntan al mismo archivo comparten entrada
+    fn key(path: &str) -> PathBuf {
+        Path::new(path)
+            .canonicalize()
+            .unwrap_or_else(|_| PathBuf::from(path))
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use glam::Vec2;
+
+use super::{
+    assets::Assets,
+    shape::Shape,
+    sprite::{FrameRect, Sprite, SpriteSheet},
+    texture::{Texture, TextureHandle},
+};
+
+/// Reúne muchas imágenes pequeñas para empaquetarlas en una sola textura
+#[derive(Debug)]
+pub struct AtlasBuilder {
+    images: Vec<(String, TextureHandle)>,
+    padding: u32,
+    max_size: u32,
+}
+
+impl Default for AtlasBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AtlasBuilder {
+    pub fn new() -> Self {
+        Self {
+            images: Vec::new(),
+            padding: 1,
+            max_size: 4096,
+        }
+    }
+
+    /// Píxeles libres entre regiones, evita que el filtrado mezcle vecinas
+    pub fn with_padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Ancho y alto máximos permitidos para el atlas
+    pub fn with_max_size(mut self, max_size: u32) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn add(&mut self, key: &str, texture: impl Into<TextureHandle>) -> &mut Self {
+        self.images.push((key.to_string(), texture.into()));
+        self
+    }
+
+    /// Carga la imagen (vía `assets`) usando su ruta como clave
+    pub fn add_file(&mut self, assets: &mut Assets, path: &str) -> Result<&mut Self, String> {
+        let texture = assets.load_texture(path)?;
+        Ok(self.add(path, texture))
+    }
+
+    /// Empaqueta con un skyline bottom-left, probando anchos crecientes
+    pub fn build(&self) -> Result<Atlas, String> {
+        if self.images.is_empty() {
+            return Err("Atlas without images".to_string());
+        }
+
+        let pad = self.padding;
+        let sizes: Vec<(u32, u32)> = self
+            .images
+            .iter()
+            .map(|(_, texture)| {
+                let (w, h) = texture.size();
+                (w + pad, h + pad)
+            })
+            .collect();
+
+        let widest = sizes.iter().map(|s| s.0).max().unwrap_or(1);
+        let area: u64 = sizes.iter().map(|&(w, h)| w as u64 * h as u64).sum();
+
+        // Orden por altura descendente: el skyline queda más parejo
+        let mut order: Vec<usize> = (0..sizes.len()).collect();
+        order.sort_by_key(|&i| std::cmp::Reverse((sizes[i].1, sizes[i].0)));
+
+        let mut width = ((area as f64).sqrt().ceil() as u32).max(widest).next_power_of_two();
+        loop {
+            if width > self.max_size.max(widest) {
+                return Err(format!(
+                    "Atlas does not fit in {}x{}",
+                    self.max_size, self.max_size
+                ));
+            }
+
+            let mut skyline = Skyline::new(width);
+            let mut placed = vec![(0, 0); sizes.len()];
+            for &index in &order {
+                let (w, h) = sizes[index];
+                placed[index] = skyline.insert(w, h);
+            }
+
+            let height = skyline.height();
+            if height <= self.max_size {
+                return Ok(self.compose(width, height, &placed));
+            }
+            width *= 2;
+        }
+    }
+
+    fn compose(&self, width: u32, height: u32, placed: &[(u32, u32)]) -> Atlas {
+        let mut data = vec![0u32; (width * height) as usize];
+        let mut regions = HashMap::new();
+
+        for ((key, texture), &(x, y)) in self.images.iter().zip(placed) {
+            let texture = texture.read();
+            let (w, h) = texture.size;
+            for row in 0..h {
+                let src = &texture.data[(row * w) as usize..((row + 1) * w) as usize];
+                let start = ((y + row) * width + x) as usize;
+                data[start..start + w as usize].copy_from_slice(src);
+            }
+            regions.insert(key.clone(), FrameRect::new(x, y, w, h));
+        }
+
+        Atlas {
+            texture: TextureHandle::new(Texture::new(width, height, data)),
+            regions,
+        }
+    }
+}
+
+/// Línea del horizonte: segmentos (x, y, ancho) ordenados por x
+struct Skyline {
+    width: u32,
+    segments: Vec<(u32, u32, u32)>,
+}
+
+impl Skyline {
+    fn new(width: u32) -> Self {
+        Self {
+            width,
+            segments: vec![(0, 0, width)],
+        }
+    }
+
+    fn height(&self) -> u32 {
+        self.segments.iter().map(|s| s.1).max().unwrap_or(0)
+    }
+
+    /// Busca la posición más baja (y luego más a la izquierda) donde cabe el rectángulo
+    fn insert(&mut self, w: u32, h: u32) -> (u32, u32) {
+        let mut best: Option<(usize, u32, u32)> = None;
+
+        for start in 0..self.segments.len() {
+            let x = self.segments[start].0;
+            if x + w > self.width {
+                break;
+            }
+            let mut y = 0;
+            let mut covered = 0;
+            for segment in &self.segments[start..] {
+                if covered >= w {
+                    break;
+                }
+                y = y.max(segment.1);
+                covered += segment.2;
+            }
+            if best.is_none_or(|(_, best_x, best_y)| y < best_y || (y == best_y && x < best_x)) {
+                best = Some((start, x, y));
+            }
+        }
+
+        let (start, x, y) = best.unwrap_or((0, 0, self.height()));
+        self.raise(start, x, y + h, w);
+        (x, y)
+    }
+
+    /// Coloca un segmento nuevo y recorta los que quedaron debajo
+    fn raise(&mut self, start: usize, x: u32, top: u32, w: u32) {
+        let end = x + w;
+        let index = start;
+        while index < self.segments.len() {
+            let (sx, sy, sw) = self.segments[index];
+            if sx >= end {
+                break;
+            }
+            if sx + sw <= end {
+                self.segments.remove(index);
+            } else {
+                self.segments[index] = (end, sy, sx + sw - end);
+                break;
+            }
+        }
+        self.segments.insert(start, (x, top, w));
+
+        // Une segmentos contiguos a la misma altura
+        let mut i = 0;
+        while i + 1 < self.segments.len() {
+            if self.segments[i].1 == self.segments[i + 1].1 {
+                self.segments[i].2 += self.segments[i + 1].2;
+                self.segments.remove(i + 1);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+/// Textura única con muchas regiones con nombre
+#[derive(Debug, Clone)]
+pub struct Atlas {
+    pub texture: TextureHandle,
+    regions: HashMap<String, FrameRect>,
+}
+
+impl Atlas {
+    pub fn region(&self, key: &str) -> Option<FrameRect> {
+        self.regions.get(key).copied()
+    }
+
+    pub fn regions(&self) -> impl Iterator<Item = (&str, FrameRect)> {
+        self.regions.iter().map(|(key, rect)| (key.as_str(), *rect))
+    }
+
+    /// Esquinas UV (mín, máx) de la región dentro del atlas
+    pub fn uv_rect(&self, key: &str) -> Option<(Vec2, Vec2)> {
+        Some(self.region(key)?.uv(self.texture.size()))
+    }
+
+    /// Reasigna las UV del shape (pensadas para la imagen sola, 0..1) a su región
+    /// del atlas y cambia su textura por la del atlas.
+    pub fn remap_shape(&self, shape: &mut Shape, key: &str) -> bool {
+        let Some((min, max)) = self.uv_rect(key) else {
+            return false;
+        };
+        for vertex in shape.vertices.iter_mut() {
+            vertex.uv = min + vertex.uv.clamp(Vec2::ZERO, Vec2::ONE) * (max - min);
+        }
+        shape.material.texture = Some(self.texture.clone());
+        true
+    }
+
+    pub fn sprite(&self, key: &str) -> Option<Sprite> {
+        Some(Sprite::new(self.texture.clone(), self.region(key)?))
+    }
+
+    /// Hoja de sprites con las regiones en el orden de `keys`
+    pub fn sheet(&self, keys: &[&str]) -> SpriteSheet {
+        let frames = keys.iter().filter_map(|key| self.region(key)).collect();
+        SpriteSheet::from_rects(self.texture.clone(), frames)
+    }
+}
@@ -1,4 +1,6 @@
-use rand::random_range;
+use rand::Rng;
+
+use super::random::with_rng;
 
 #[derive(Clone, Copy)]
 pub struct Float(pub f32);
@@ -26,8 +28,13 @@ impl PartialEq for Float {
 }
 impl Eq for Float {}
 
+/// Valor de 0 a `faces - 1` con el generador del hilo (sembrado por `Galar`)
 pub fn roll_dice(faces: u32) -> u32 {
-    random_range(0..faces)
+    with_rng(|rng| roll_dice_with(rng, faces))
+}
+
+pub fn roll_dice_with<R: Rng + ?Sized>(rng: &mut R, faces: u32) -> u32 {
+    rng.random_range(0..faces)
 }
 
 /// Implementa la detección de dos objetos.
@@ -67,7 +74,7 @@ pub fn soft_proximity(x1: f32, y1: f32, x2: f32, y2: f32, k: f32) -> f32 {
     1.0 - (-k * d).exp() // Valor suavizado entre 0 y 1
 }
 
-/// ```
+/// ```text
 /// Value: valor a reasignar.
 /// Start1: límite inferior del rango actual del valor
 /// Stop1: límite superior del rango actual del valor
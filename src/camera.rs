@@ -0,0 +1,149 @@
+use glam::{Affine2, Vec2};
+
+/// Cámara 2D: transforma coordenadas del mundo a la pantalla.
+///
+/// `position` es el punto del mundo que se ve en `offset` (coordenadas de pantalla).
+/// Con los valores por defecto la vista es la identidad: mundo == pantalla.
+#[derive(Debug, Clone, Copy)]
+pub struct Camera2D {
+    pub position: Vec2,
+    pub offset: Vec2,
+    pub zoom: f32,
+    pub rotation: f32, // en radianes
+    pub min_zoom: f32,
+    pub max_zoom: f32,
+    /// Límites (mín, máx) del mundo para `position`
+    pub bounds: Option<(Vec2, Vec2)>,
+}
+
+impl Default for Camera2D {
+    fn default() -> Self {
+        Self {
+            position: Vec2::ZERO,
+            offset: Vec2::ZERO,
+            zoom: 1.0,
+            rotation: 0.0,
+            min_zoom: 0.01,
+            max_zoom: 100.0,
+            bounds: None,
+        }
+    }
+}
+
+impl Camera2D {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cámara que mira al centro de una pantalla `width` x `height`,
+    /// de modo que el zoom y la rotación ocurren alrededor del centro.
+    pub fn centered(width: usize, height: usize) -> Self {
+        let center = Vec2::new(width as f32, height as f32) * 0.5;
+        Self {
+            position: center,
+            offset: center,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_position(mut self, x: f32, y: f32) -> Self {
+        self.position = Vec2::new(x, y);
+        self.clamp_to_bounds();
+        self
+    }
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = zoom.clamp(self.min_zoom, self.max_zoom);
+        self
+    }
+    pub fn with_rotation(mut self, degree: f32) -> Self {
+        self.rotation = degree.to_radians();
+        self
+    }
+    pub fn with_bounds(mut self, min: Vec2, max: Vec2) -> Self {
+        self.set_bounds(Some((min, max)));
+        self
+    }
+
+    pub fn set_bounds(&mut self, bounds: Option<(Vec2, Vec2)>) {
+        self.bounds = bounds;
+        self.clamp_to_bounds();
+    }
+
+    /// Vuelve a la vista identidad, conservando límites de zoom y bounds
+    pub fn reset(&mut self) {
+        self.position = self.offset;
+        self.zoom = 1.0;
+        self.rotation = 0.0;
+        self.clamp_to_bounds();
+    }
+
+    /// Matriz mundo → pantalla
+    pub fn view_matrix(&self) -> Affine2 {
+        Affine2::from_translation(self.offset)
+            * Affine2::from_scale_angle_translation(Vec2::splat(self.zoom), -self.rotation, Vec2::ZERO)
+            * Affine2::from_translation(-self.position)
+    }
+
+    /// Matriz pantalla → mundo
+    pub fn inverse_view_matrix(&self) -> Affine2 {
+        self.view_matrix().inverse()
+    }
+
+    pub fn world_to_screen(&self, world: Vec2) -> Vec2 {
+        self.view_matrix().transform_point2(world)
+    }
+
+    pub fn screen_to_world(&self, screen: Vec2) -> Vec2 {
+        self.inverse_view_matrix().transform_point2(screen)
+    }
+
+    /// Indica si la cámara no altera las coordenadas
+    pub fn is_identity(&self) -> bool {
+        self.zoom == 1.0 && self.rotation == 0.0 && self.position == self.offset
+    }
+
+    /// Desplaza la vista según un delta en píxeles de pantalla (arrastre con el Mouse)
+    pub fn pan(&mut self, screen_delta: Vec2) {
+        let world_delta = Vec2::from_angle(self.rotation).rotate(screen_delta) / self.zoom;
+        self.position -= world_delta;
+        self.clamp_to_bounds();
+    }
+
+    /// Desplaza la vista en unidades del mundo
+    pub fn pan_world(&mut self, world_delta: Vec2) {
+        self.position += world_delta;
+        self.clamp_to_bounds();
+    }
+
+    /// Multiplica el zoom manteniendo fijo el punto del mundo bajo `screen_point`
+    pub fn zoom_at(&mut self, screen_point: Vec2, factor: f32) {
+        let anchor = self.screen_to_world(screen_point);
+        self.zoom = (self.zoom * factor).clamp(self.min_zoom, self.max_zoom);
+        self.keep_anchor(anchor, screen_point);
+    }
+
+    /// Rota la vista (radianes) alrededor del punto de pantalla `screen_point`
+    pub fn rotate_at(&mut self, screen_point: Vec2, angle: f32) {
+        let anchor = self.screen_to_world(screen_point);
+        self.rotation += angle;
+        self.keep_anchor(anchor, screen_point);
+    }
+
+    /// Rota la vista (radianes) alrededor de `offset`
+    pub fn rotate(&mut self, angle: f32) {
+        self.rotation += angle;
+    }
+
+    /// Ajusta `position` para que `world` se vea en `screen`
+    fn keep_anchor(&mut self, world: Vec2, screen: Vec2) {
+        let delta = Vec2::from_angle(self.rotation).rotate(screen - self.offset) / self.zoom;
+        self.position = world - delta;
+        self.clamp_to_bounds();
+    }
+
+    fn clamp_to_bounds(&mut self) {
+        if let Some((min, max)) = self.bounds {
+            self.position = self.position.clamp(min.min(max), max.max(min));
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use std::ops::Range;
+
+use super::{camera::Camera2D, colores::Color, linear_color::blend_over};
+
+/// Superficie de píxeles AARRGGBB donde se puede dibujar.
+///
+/// Basta con implementar `size`, `get_pixel` y `put_pixel`; todas las primitivas
+/// (`draw_line`, `draw_rect`, `draw_circle`, ... y `draw_shape`) funcionan sobre
+/// cualquier implementación: la ventana (`ConfigGalar`), un `RenderTarget`,
+/// un `BufferCanvas` sobre un `&mut [u32]` o un `image::RgbaImage`.
+pub trait Canvas {
+    /// Ancho y alto en píxeles
+    fn size(&self) -> (usize, usize);
+
+    /// Color en (x, y), `None` fuera de los límites
+    fn get_pixel(&self, x: usize, y: usize) -> Option<u32>;
+
+    /// Escribe el color en (x, y); fuera de los límites no hace nada
+    fn put_pixel(&mut self, x: usize, y: usize, color: u32);
+
+    /// Compone el color sobre el píxel existente según su alfa, en luz lineal
+    fn blend_pixel(&mut self, x: usize, y: usize, color: u32) {
+        match color >> 24 {
+            0 => {}
+            255 => self.put_pixel(x, y, color),
+            _ => {
+                if let Some(dst) = self.get_pixel(x, y) {
+                    self.put_pixel(x, y, blend_over(dst, color));
+                }
+            }
+        }
+    }
+
+    /// Rellena la fila `y` desde `x0` hasta `x1` (exclusivo), recortando a los límites
+    fn fill_span(&mut self, x0: usize, x1: usize, y: usize, color: u32) {
+        let (width, height) = self.size();
+        if y >= height {
+            return;
+        }
+        for x in x0..x1.min(width) {
+            self.put_pixel(x, y, color);
+        }
+    }
+
+    /// Cámara que `draw_shape` aplica después del modelo, si la hay
+    fn camera(&self) -> Option<&Camera2D> {
+        None
+    }
+
+    /// Rellena todo el canvas
+    fn clear(&mut self, color: u32) {
+        let (width, height) = self.size();
+        for y in 0..height {
+            self.fill_span(0, width, y, color);
+        }
+    }
+
+    /// Método para dibujar directamente, con el índice de `iter_d1`
+    fn explicit_draw(&mut self, index: usize, color: u32) {
+        let width = self.size().0.max(1);
+        self.put_pixel(index % width, index / width, color);
+    }
+
+    /// Iteración sobre una dimención segun la resolución del canvas
+    fn iter_d1(&self) -> Range<usize> {
+        let (width, height) = self.size();
+        0..(width * height)
+    }
+
+    /// Métodos para dibujar con validación de límites
+    fn draw_pixel(&mut self, x: usize, y: usize, color: u32) {
+        self.put_pixel(x, y, color);
+    }
+
+    /// Optimización: dibujar segmentos de línea de manera eficiente
+    fn draw_line(&mut self, x0: usize, y0: usize, x1: usize, y1: usize, color: u32) {
+        // Implementación de Bresenham para líneas
+
+        let mut x0 = x0 as isize;
+        let mut y0 = y0 as isize;
+        let x1 = x1 as isize;
+        let y1 = y1 as isize;
+
+        let dx = (x1 - x0).abs();
+        let dy = -(y1 - y0).abs();
+        let sx = if x0 < x1 { 1 } else { -1 };
+        let sy = if y0 < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+
+        loop {
+            if x0 >= 0 && y0 >= 0 {
+                self.put_pixel(x0 as usize, y0 as usize, color);
+            }
+
+            if x0 == x1 && y0 == y1 {
+                break;
+            }
+
+            let e2 = 2 * err;
+            if e2 >= dy {
+                if x0 == x1 {
+                    break;
+                }
+                err += dy;
+                x0 += sx;
+            }
+            if e2 <= dx {
+                if y0 == y1 {
+                    break;
+                }
+                err += dx;
+                y0 += sy;
+            }
+        }
+    }
+
+    /// Métodos adicionales para formas rectangulares
+    fn draw_rect(&mut self, x: usize, y: usize, width: usize, height: usize, color: u32) {
+        let y_max = (y + height).min(self.size().1);
+
+        for curr_y in y..y_max {
+            self.fill_span(x, x + width, curr_y, color);
+        }
+    }
+
+    /// Métodos adicionales para formas circulares
+    fn draw_circle(&mut self, cx: usize, cy: usize, radius: usize, color: u32) {
+        let radius = radius as isize;
+        let r2 = radius * radius;
+
+        for y in -radius..=radius {
+            let py = cy as isize + y;
+            if py < 0 {
+                continue;
+            }
+            // Media cuerda de la fila: el círculo se rellena por spans
+            let mut half = 0;
+            while (half + 1) * (half + 1) + y * y <= r2 {
+                half += 1;
+            }
+            let x0 = (cx as isize - half).max(0) as usize;
+            let x1 = cx as isize + half + 1;
+            if x1 > 0 {
+                self.fill_span(x0, x1 as usize, py as usize, color);
+            }
+        }
+    }
+
+    /// WIREFRAME: Métodos adicionales para formas circulares
+    fn draw_circle_outline(&mut self, cx: isize, cy: isize, radius: isize, color: Color) {
+        let hex_color = color.to_hex();
+        let r2 = radius * radius;
+        let inner_r2 = (radius - 1) * (radius - 1);
+
+        for y in -radius..=radius {
+            for x in -radius..=radius {
+                let dist2 = x * x + y * y;
+                if dist2 <= r2 && dist2 >= inner_r2 {
+                    let px = cx + x;
+                    let py = cy + y;
+                    if px >= 0 && py >= 0 {
+                        self.put_pixel(px as usize, py as usize, hex_color);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `Canvas` prestado sobre un buffer plano de `width * height` píxeles
+#[derive(Debug)]
+pub struct BufferCanvas<'a> {
+    pixels: &'a mut [u32],
+    width: usize,
+    height: usize,
+}
+
+impl<'a> BufferCanvas<'a> {
+    pub fn new(pixels: &'a mut [u32], width: usize, height: usize) -> Self {
+        assert!(
+            pixels.len() >= width * height,
+            "Buffer of {} pixels is smaller than {}x{}",
+            pixels.len(),
+            width,
+            height
+        );
+        Self {
+            pixels,
+            width,
+            height,
+        }
+    }
+}
+
+impl Canvas for BufferCanvas<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Option<u32> {
+        slice_get(self.pixels, self.width, self.height, x, y)
+    }
+
+    fn put_pixel(&mut self, x: usize, y: usize, color: u32) {
+        slice_put(self.pixels, self.width, self.height, x, y, color);
+    }
+
+    fn fill_span(&mut self, x0: usize, x1: usize, y: usize, color: u32) {
+        slice_fill_span(self.pixels, self.width, self.height, x0, x1, y, color);
+    }
+}
+
+/// Los píxeles se convierten entre AARRGGBB y RGBA8.
+/// Ojo: `RgbaImage` tiene sus propios `get_pixel`/`put_pixel`; para los del
+/// canvas usa `Canvas::put_pixel(&mut image, ..)`.
+impl Canvas for image::RgbaImage {
+    fn size(&self) -> (usize, usize) {
+        (self.width() as usize, self.height() as usize)
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Option<u32> {
+        let [r, g, b, a] = self.get_pixel_checked(x as u32, y as u32)?.0;
+        Some((a as u32) << 24 | (r as u32) << 16 | (g as u32) << 8 | b as u32)
+    }
+
+    fn put_pixel(&mut self, x: usize, y: usize, color: u32) {
+        if let Some(pixel) = self.get_pixel_mut_checked(x as u32, y as u32) {
+            let [a, r, g, b] = color.to_be_bytes();
+            pixel.0 = [r, g, b, a];
+        }
+    }
+}
+
+// Helpers compartidos por los canvas respaldados por un slice
+
+#[inline]
+pub(crate) fn slice_get(pixels: &[u32], width: usize, height: usize, x: usize, y: usize) -> Option<u32> {
+    if x < width && y < height {
+        pixels.get(y * width + x).copied()
+    } else {
+        None
+    }
+}
+
+#[inline]
+pub(crate) fn slice_put(pixels: &mut [u32], width: usize, height: usize, x: usize, y: usize, color: u32) {
+    if x < width && y < height {
+        pixels[y * width + x] = color;
+    }
+}
+
+#[inline]
+pub(crate) fn slice_fill_span(
+    pixels: &mut [u32],
+    width: usize,
+    height: usize,
+    x0: usize,
+    x1: usize,
+    y: usize,
+    color: u32,
+) {
+    let x1 = x1.min(width);
+    if y < height && x0 < x1 {
+        pixels[y * width + x0..y * width + x1].fill(color);
+    }
+}
@@ -0,0 +1,767 @@
+use std::{fmt, str::FromStr};
+
+use super::{
+    color_space::{from_polar, srgb_to_linear},
+    colores::Color,
+};
+
+/// Error al interpretar un color desde texto
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    Empty,
+    InvalidHex(String),
+    UnknownName(String),
+    UnknownFunction(String),
+    UnknownColorSpace(String),
+    InvalidSyntax(String),
+    InvalidComponent(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty color string"),
+            Self::InvalidHex(value) => write!(f, "invalid hex color '{}'", value),
+            Self::UnknownName(value) => write!(f, "unknown color name '{}'", value),
+            Self::UnknownFunction(value) => write!(f, "unknown color function '{}()'", value),
+            Self::UnknownColorSpace(value) => write!(f, "unsupported color space '{}'", value),
+            Self::InvalidSyntax(value) => write!(f, "invalid color syntax '{}'", value),
+            Self::InvalidComponent(value) => write!(f, "invalid color component '{}'", value),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+impl From<ParseColorError> for String {
+    fn from(error: ParseColorError) -> Self {
+        error.to_string()
+    }
+}
+
+impl Color {
+    /// Desde formato AARRGGBB (inverso de `to_hex`)
+    pub fn from_hex(hex: u32) -> Self {
+        Self {
+            a: (hex >> 24) as u8,
+            r: (hex >> 16) as u8,
+            g: (hex >> 8) as u8,
+            b: hex as u8,
+        }
+    }
+
+    /// Desde formato RRGGBBAA (inverso de `to_rgba_hex`)
+    pub fn from_rgba_hex(hex: u32) -> Self {
+        Self {
+            r: (hex >> 24) as u8,
+            g: (hex >> 16) as u8,
+            b: (hex >> 8) as u8,
+            a: hex as u8,
+        }
+    }
+
+    /// Color con nombre CSS (`"rebeccapurple"`, `"transparent"`...), sin distinguir mayúsculas
+    pub fn named(name: &str) -> Option<Self> {
+        let name = name.trim().to_ascii_lowercase();
+        if name == "transparent" {
+            return Some(Self::rgba(0, 0, 0, 0));
+        }
+        CSS_NAMES
+            .binary_search_by(|(css, _)| css.cmp(&name.as_str()))
+            .ok()
+            .map(|index| Self::from_hex(0xFF00_0000 | CSS_NAMES[index].1))
+    }
+
+    /// Nombre CSS del color si coincide exactamente con alguno (opaco)
+    pub fn css_name(&self) -> Option<&'static str> {
+        if self.a != 255 {
+            return (self.to_hex() == 0).then_some("transparent");
+        }
+        let rgb = self.to_hex() & 0x00FF_FFFF;
+        CSS_NAMES
+            .iter()
+            .find(|(_, value)| *value == rgb)
+            .map(|(name, _)| *name)
+    }
+}
+
+impl From<u32> for Color {
+    fn from(hex: u32) -> Self {
+        Self::from_hex(hex)
+    }
+}
+
+/// `#rrggbb`, o `#rrggbbaa` si el color no es opaco; se puede volver a leer con `parse`
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)?;
+        if self.a != 255 {
+            write!(f, "{:02x}", self.a)?;
+        }
+        Ok(())
+    }
+}
+
+/// Sintaxis de CSS Color Level 4:
+/// - `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`
+/// - nombres CSS y `transparent`
+/// - `rgb()`/`rgba()`, `hsl()`/`hsla()`, `hwb()`, `lab()`, `lch()`, `oklab()`, `oklch()`
+/// - `color(<espacio> r g b)` con `srgb`, `srgb-linear`, `display-p3`, `a98-rgb`,
+///   `prophoto-rgb`, `rec2020`, `xyz`, `xyz-d50` y `xyz-d65`
+///
+/// Acepta la forma con comas y la moderna con espacios y `/ alpha`, porcentajes,
+/// `none` y tonos en `deg`, `rad`, `grad` o `turn`. Los colores fuera de sRGB se recortan.
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+        let lower = text.to_ascii_lowercase();
+
+        if let Some(hex) = lower.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(|| ParseColorError::InvalidHex(text.to_string()));
+        }
+
+        if let Some(open) = lower.find('(') {
+            let Some(args) = lower[open + 1..].strip_suffix(')') else {
+                return Err(ParseColorError::InvalidSyntax(text.to_string()));
+            };
+            return parse_function(lower[..open].trim(), args, text);
+        }
+
+        Color::named(&lower).ok_or_else(|| ParseColorError::UnknownName(text.to_string()))
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    // Un dígito por canal se repite: #f80 == #ff8800
+    let nibble = |shift: u32| ((value >> shift) & 0xF) as u8 * 17;
+    match hex.len() {
+        3 => Some(Color::rgb(nibble(8), nibble(4), nibble(0))),
+        4 => Some(Color::rgba(nibble(12), nibble(8), nibble(4), nibble(0))),
+        6 => Some(Color::from_hex(0xFF00_0000 | value)),
+        8 => Some(Color::from_rgba_hex(value)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Component {
+    Number(f32),
+    Percent(f32),
+    /// Ángulo en grados
+    Angle(f32),
+    None,
+}
+
+impl Component {
+    fn parse(token: &str) -> Result<Self, ParseColorError> {
+        let invalid = || ParseColorError::InvalidComponent(token.to_string());
+        if token == "none" {
+            return Ok(Self::None);
+        }
+        let number = |value: &str| value.parse::<f32>().ok().filter(|n| n.is_finite());
+
+        if let Some(value) = token.strip_suffix('%') {
+            return number(value).map(Self::Percent).ok_or_else(invalid);
+        }
+        // "grad" antes que "rad"
+        let units = [
+            ("deg", 1.0),
+            ("grad", 0.9),
+            ("rad", 180.0 / std::f32::consts::PI),
+            ("turn", 360.0),
+        ];
+        for (unit, to_degrees) in units {
+            if let Some(value) = token.strip_suffix(unit) {
+                return number(value)
+                    .map(|n| Self::Angle(n * to_degrees))
+                    .ok_or_else(invalid);
+            }
+        }
+        number(token).map(Self::Number).ok_or_else(invalid)
+    }
+
+    /// Número donde `100%` equivale a `reference`
+    fn value(self, reference: f32) -> Result<f32, ParseColorError> {
+        match self {
+            Self::Number(n) => Ok(n),
+            Self::Percent(p) => Ok(p / 100.0 * reference),
+            Self::None => Ok(0.0),
+            Self::Angle(_) => Err(ParseColorError::InvalidComponent(format!("{:?}", self))),
+        }
+    }
+
+    /// Tono en grados; los números sin unidad son grados
+    fn hue(self) -> Result<f32, ParseColorError> {
+        match self {
+            Self::Number(n) | Self::Angle(n) => Ok(n),
+            Self::None => Ok(0.0),
+            Self::Percent(_) => Err(ParseColorError::InvalidComponent(format!("{:?}", self))),
+        }
+    }
+
+    fn alpha(self) -> Result<u8, ParseColorError> {
+        Ok((self.value(1.0)?.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+}
+
+fn parse_function(name: &str, args: &str, text: &str) -> Result<Color, ParseColorError> {
+    let syntax = || ParseColorError::InvalidSyntax(text.to_string());
+
+    // color(<espacio> c1 c2 c3 / a): el primer token es el espacio
+    let (space, args) = if name == "color" {
+        let args = args.trim_start();
+        let end = args.find(char::is_whitespace).ok_or_else(syntax)?;
+        (Some(&args[..end]), &args[end..])
+    } else {
+        (None, args)
+    };
+
+    // Forma antigua con comas o moderna con espacios y "/ alpha"
+    let (channels, alpha): (Vec<&str>, Option<&str>) = if args.contains(',') {
+        let mut parts: Vec<&str> = args.split(',').map(str::trim).collect();
+        let alpha = if parts.len() == 4 { parts.pop() } else { None };
+        (parts, alpha)
+    } else {
+        let mut halves = args.splitn(2, '/');
+        let channels = halves
+            .next()
+            .unwrap_or_default()
+            .split_whitespace()
+            .collect();
+        (channels, halves.next().map(str::trim))
+    };
+
+    let [c0, c1, c2] = channels
+        .iter()
+        .map(|token| Component::parse(token))
+        .collect::<Result<Vec<_>, _>>()?
+        .try_into()
+        .map_err(|_| syntax())?;
+    let alpha = match alpha {
+        Some(token) if !token.is_empty() => Component::parse(token)?.alpha()?,
+        Some(_) => return Err(syntax()),
+        None => 255,
+    };
+
+    let mut color = match name {
+        "rgb" | "rgba" => {
+            let channel = |c: Component| -> Result<u8, ParseColorError> {
+                Ok(c.value(255.0)?.clamp(0.0, 255.0).round() as u8)
+            };
+            Color::rgb(channel(c0)?, channel(c1)?, channel(c2)?)
+        }
+        "hsl" | "hsla" => Color::hsl(
+            c0.hue()?,
+            c1.value(100.0)? / 100.0,
+            c2.value(100.0)? / 100.0,
+        ),
+        "hwb" => Color::hwb(
+            c0.hue()?,
+            c1.value(100.0)? / 100.0,
+            c2.value(100.0)? / 100.0,
+        ),
+        "lab" => lab_d50(c0.value(100.0)?, c1.value(125.0)?, c2.value(125.0)?),
+        "lch" => {
+            let (a, b) = from_polar(c1.value(150.0)?.max(0.0), c2.hue()?);
+            lab_d50(c0.value(100.0)?, a, b)
+        }
+        "oklab" => Color::oklab(c0.value(1.0)?, c1.value(0.4)?, c2.value(0.4)?),
+        "oklch" => Color::oklch(c0.value(1.0)?, c1.value(0.4)?, c2.hue()?),
+        "color" => {
+            let values = [c0.value(1.0)?, c1.value(1.0)?, c2.value(1.0)?];
+            predefined(space.unwrap_or_default(), values)?
+        }
+        _ => return Err(ParseColorError::UnknownFunction(name.to_string())),
+    };
+    color.a = alpha;
+    Ok(color)
+}
+
+// CSS define lab()/lch() con blanco D50; se adapta a D65 antes de pasar a sRGB
+const WHITE_D50: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+const BRADFORD_D50_TO_D65: [[f64; 3]; 3] = [
+    [0.955473421488075, -0.02309845494876471, 0.06325924320057072],
+    [
+        -0.0283697093338637,
+        1.0099953980813041,
+        0.021041441191917323,
+    ],
+    [
+        0.012314014864481998,
+        -0.020507649298898964,
+        1.330365926242124,
+    ],
+];
+
+const DISPLAY_P3_TO_XYZ: [[f64; 3]; 3] = [
+    [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+    [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+    [0.0, 0.04511338185890264, 1.043944368900976],
+];
+
+const A98_TO_XYZ: [[f64; 3]; 3] = [
+    [0.5766690429101305, 0.1855582379065463, 0.1882286462349947],
+    [0.29734497525053605, 0.6273635662554661, 0.07529145849399788],
+    [0.02703136138641234, 0.07068885253582723, 0.9913375368376388],
+];
+
+// ProPhoto es D50
+const PROPHOTO_TO_XYZ_D50: [[f64; 3]; 3] = [
+    [0.7977604896723027, 0.13518583717574031, 0.0313493495815248],
+    [
+        0.2880711282292934,
+        0.7118432178101014,
+        0.00008565396060525902,
+    ],
+    [0.0, 0.0, 0.8251046025104601],
+];
+
+const REC2020_TO_XYZ: [[f64; 3]; 3] = [
+    [0.6369580483012914, 0.14461690358620832, 0.1688809751641721],
+    [0.2627002120112671, 0.6779980715188708, 0.05930171646986196],
+    [0.0, 0.028072693049087428, 1.060985057710791],
+];
+
+fn mul(matrix: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+fn from_xyz_d65([x, y, z]: [f64; 3]) -> Color {
+    Color::from_xyz(x as f32, y as f32, z as f32)
+}
+
+fn from_xyz_d50(xyz: [f64; 3]) -> Color {
+    from_xyz_d65(mul(&BRADFORD_D50_TO_D65, xyz))
+}
+
+fn lab_d50(l: f32, a: f32, b: f32) -> Color {
+    let (l, a, b) = (l as f64, a as f64, b as f64);
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    let inverse = |t: f64| {
+        if t > 6.0 / 29.0 {
+            t * t * t
+        } else {
+            3.0 * (6.0f64 / 29.0).powi(2) * (t - 4.0 / 29.0)
+        }
+    };
+    let [wx, wy, wz] = WHITE_D50;
+    from_xyz_d50([inverse(fx) * wx, inverse(fy) * wy, inverse(fz) * wz])
+}
+
+fn predefined(space: &str, values: [f32; 3]) -> Result<Color, ParseColorError> {
+    let v = values.map(f64::from);
+    let signed = |value: f64, curve: &dyn Fn(f64) -> f64| value.signum() * curve(value.abs());
+    let srgb_curve = |value: f64| srgb_to_linear(value as f32) as f64;
+
+    let color = match space {
+        "srgb" => Color::from_unit_rgb(values),
+        "srgb-linear" => Color::from_linear(values[0], values[1], values[2]),
+        "display-p3" => from_xyz_d65(mul(
+            &DISPLAY_P3_TO_XYZ,
+            v.map(|value| signed(value, &srgb_curve)),
+        )),
+        "a98-rgb" => from_xyz_d65(mul(
+            &A98_TO_XYZ,
+            v.map(|value| signed(value, &|c| c.powf(563.0 / 256.0))),
+        )),
+        "prophoto-rgb" => {
+            let curve = |c: f64| {
+                if c <= 16.0 / 512.0 {
+                    c / 16.0
+                } else {
+                    c.powf(1.8)
+                }
+            };
+            from_xyz_d50(mul(
+                &PROPHOTO_TO_XYZ_D50,
+                v.map(|value| signed(value, &curve)),
+            ))
+        }
+        "rec2020" => {
+            const ALPHA: f64 = 1.09929682680944;
+            const BETA: f64 = 0.018053968510807;
+            let curve = |c: f64| {
+                if c < BETA * 4.5 {
+                    c / 4.5
+                } else {
+                    ((c + ALPHA - 1.0) / ALPHA).powf(1.0 / 0.45)
+                }
+            };
+            from_xyz_d65(mul(&REC2020_TO_XYZ, v.map(|value| signed(value, &curve))))
+        }
+        "xyz" | "xyz-d65" => from_xyz_d65(v),
+        "xyz-d50" => from_xyz_d50(v),
+        _ => return Err(ParseColorError::UnknownColorSpace(space.to_string())),
+    };
+    Ok(color)
+}
+
+// Colores con nombre de CSS, ordenados para búsqueda binaria (0xRRGGBB)
+const CSS_NAMES: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+/// Se serializa como texto (`"#rrggbb"`); al leer acepta cualquier sintaxis de `FromStr`
+/// o una lista `[r, g, b]` / `[r, g, b, a]`
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use std::fmt;
+
+    use serde::{
+        Deserialize, Deserializer, Serialize, Serializer,
+        de::{self, SeqAccess, Visitor},
+    };
+
+    use crate::colores::Color;
+
+    impl Serialize for Color {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    struct ColorVisitor;
+
+    impl<'de> Visitor<'de> for ColorVisitor {
+        type Value = Color;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a CSS color string or an [r, g, b(, a)] array")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Color, E> {
+            value.parse().map_err(E::custom)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Color, A::Error> {
+            let mut channel = |index: usize| -> Result<Option<u8>, A::Error> {
+                let value = seq.next_element()?;
+                if value.is_none() && index < 3 {
+                    return Err(de::Error::invalid_length(index, &self));
+                }
+                Ok(value)
+            };
+            let r = channel(0)?.unwrap_or_default();
+            let g = channel(1)?.unwrap_or_default();
+            let b = channel(2)?.unwrap_or_default();
+            let a = channel(3)?.unwrap_or(255);
+            Ok(Color::rgba(r, g, b, a))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Color {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(ColorVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> [u8; 4] {
+        let color: Color = text.parse().unwrap_or_else(|e| panic!("{text}: {e}"));
+        [color.r, color.g, color.b, color.a]
+    }
+
+    fn assert_close(text: &str, expected: [u8; 4]) {
+        let color = parse(text);
+        for (got, want) in color.iter().zip(expected) {
+            assert!(
+                got.abs_diff(want) <= 1,
+                "{text}: got {color:?}, expected {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn hex_layouts() {
+        assert_eq!(parse("#f80"), [0xff, 0x88, 0x00, 0xff]);
+        assert_eq!(parse("#f808"), [0xff, 0x88, 0x00, 0x88]);
+        assert_eq!(parse("#1a2B3c"), [0x1a, 0x2b, 0x3c, 0xff]);
+        assert_eq!(parse("  #1a2b3c80 "), [0x1a, 0x2b, 0x3c, 0x80]);
+        assert_eq!(Color::from(0x801a2b3c).to_rgba_hex(), 0x1a2b3c80);
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(parse("rebeccapurple"), [0x66, 0x33, 0x99, 0xff]);
+        assert_eq!(parse("RebeccaPurple"), [0x66, 0x33, 0x99, 0xff]);
+        assert_eq!(parse("transparent"), [0, 0, 0, 0]);
+        // La búsqueda binaria necesita la tabla ordenada
+        for (name, _) in CSS_NAMES {
+            assert!(Color::named(name).is_some(), "{name}");
+        }
+        assert_eq!(
+            Color::rgb(0x66, 0x33, 0x99).css_name(),
+            Some("rebeccapurple")
+        );
+    }
+
+    #[test]
+    fn rgb_and_hsl() {
+        assert_eq!(parse("rgb(255 0 0 / 50%)"), [255, 0, 0, 128]);
+        assert_eq!(parse("rgba(255, 0, 0, 0.5)"), [255, 0, 0, 128]);
+        assert_eq!(parse("rgb(100% 50% 0%)"), [255, 128, 0, 255]);
+        assert_eq!(parse("rgb(none 255 none)"), [0, 255, 0, 255]);
+        assert_eq!(parse("hsl(120deg 100% 50%)"), [0, 255, 0, 255]);
+        assert_eq!(parse("hsla(240, 100%, 50%, 1)"), [0, 0, 255, 255]);
+        assert_eq!(parse("hsl(0.5turn 100% 50%)"), [0, 255, 255, 255]);
+        assert_eq!(parse("hsl(200grad 100% 50%)"), [0, 255, 255, 255]);
+        assert_close("hsl(3.14159rad 100% 50%)", [0, 255, 255, 255]);
+        assert_eq!(parse("hwb(0 0% 0%)"), [255, 0, 0, 255]);
+        assert_eq!(parse("hwb(0 60% 60%)"), [128, 128, 128, 255]);
+    }
+
+    #[test]
+    fn lab_and_oklab() {
+        // lab()/lch() usan blanco D50: un gris neutro sigue siendo gris en sRGB
+        assert_close("lab(50% 0 0)", [119, 119, 119, 255]);
+        assert_close("lch(50 0 0)", [119, 119, 119, 255]);
+        assert_close("lab(100 0 0)", [255, 255, 255, 255]);
+        assert_close("lab(54.29 80.8 69.89)", [255, 0, 0, 255]);
+        assert_close("lch(54.29 106.84 40.85)", [255, 0, 0, 255]);
+        assert_close("oklab(0.628 0.2249 0.1258)", [255, 0, 0, 255]);
+        assert_close("oklch(62.8% 0.2577 29.23 / 0.5)", [255, 0, 0, 128]);
+    }
+
+    #[test]
+    fn predefined_spaces() {
+        assert_eq!(parse("color(srgb 1 0.5 0)"), [255, 128, 0, 255]);
+        assert_close("color(srgb-linear 0.5 0.5 0.5)", [188, 188, 188, 255]);
+        assert_close("color(xyz-d65 0.9505 1 1.089)", [255, 255, 255, 255]);
+        assert_close("color(xyz 0.9505 1 1.089)", [255, 255, 255, 255]);
+        assert_close("color(xyz-d50 0.9642 1 0.8252)", [255, 255, 255, 255]);
+        // Los blancos de cada espacio coinciden con el de sRGB
+        for space in ["display-p3", "a98-rgb", "prophoto-rgb", "rec2020"] {
+            assert_close(&format!("color({space} 1 1 1)"), [255, 255, 255, 255]);
+            assert_close(&format!("color({space} 0 0 0 / 25%)"), [0, 0, 0, 64]);
+        }
+        // El rojo de P3 queda fuera de sRGB y se recorta
+        assert_close("color(display-p3 1 0 0)", [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for color in [
+            Color::rgb(0, 0, 0),
+            Color::rgb(0x12, 0xab, 0xff),
+            Color::rgba(0x12, 0xab, 0xff, 0),
+            Color::rgba(1, 2, 3, 254),
+        ] {
+            let text = color.to_string();
+            let parsed: Color = text.parse().unwrap();
+            assert_eq!(parsed.to_hex(), color.to_hex(), "{text}");
+        }
+        assert_eq!(Color::rgb(255, 136, 0).to_string(), "#ff8800");
+        assert_eq!(Color::rgba(255, 136, 0, 16).to_string(), "#ff880010");
+    }
+
+    #[test]
+    fn errors() {
+        let error = |text: &str| text.parse::<Color>().unwrap_err();
+        assert_eq!(error("  "), ParseColorError::Empty);
+        assert!(matches!(error("#12"), ParseColorError::InvalidHex(_)));
+        assert!(matches!(error("#ggg"), ParseColorError::InvalidHex(_)));
+        assert!(matches!(
+            error("notacolor"),
+            ParseColorError::UnknownName(_)
+        ));
+        assert!(matches!(
+            error("foo(1 2 3)"),
+            ParseColorError::UnknownFunction(_)
+        ));
+        assert!(matches!(
+            error("color(p4 1 0 0)"),
+            ParseColorError::UnknownColorSpace(_)
+        ));
+        assert!(matches!(
+            error("rgb(1 2 3"),
+            ParseColorError::InvalidSyntax(_)
+        ));
+        assert!(matches!(
+            error("rgb(1 2)"),
+            ParseColorError::InvalidSyntax(_)
+        ));
+        assert!(matches!(
+            error("rgb(1 2 3 /)"),
+            ParseColorError::InvalidSyntax(_)
+        ));
+        assert!(matches!(
+            error("rgb(1 2 x)"),
+            ParseColorError::InvalidComponent(_)
+        ));
+        assert!(matches!(
+            error("rgb(10deg 0 0)"),
+            ParseColorError::InvalidComponent(_)
+        ));
+        assert!(matches!(
+            error("hsl(10% 50% 50%)"),
+            ParseColorError::InvalidComponent(_)
+        ));
+        assert_eq!(
+            String::from(error("notacolor")),
+            "unknown color name 'notacolor'"
+        );
+    }
+}
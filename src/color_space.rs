@@ -0,0 +1,347 @@
+use super::colores::Color;
+
+/// Espacio de color en el que se interpola
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSpace {
+    /// Bytes sRGB directos, como `Color::lerp`
+    #[default]
+    Srgb,
+    /// sRGB lineal: mezclas físicamente correctas de luz
+    LinearRgb,
+    Hsl,
+    Hsv,
+    Hwb,
+    /// CIE XYZ (D65)
+    Xyz,
+    /// CIE L*a*b* (D65)
+    Lab,
+    /// CIE LCh, forma polar de Lab
+    Lch,
+    /// OKLab de Björn Ottosson: perceptualmente uniforme
+    Oklab,
+    /// Forma polar de OKLab
+    Oklch,
+}
+
+impl ColorSpace {
+    /// Posición del tono en `Color::to_space`, si el espacio es polar
+    fn hue_index(self) -> Option<usize> {
+        match self {
+            Self::Hsl | Self::Hsv | Self::Hwb => Some(0),
+            Self::Lch | Self::Oklch => Some(2),
+            _ => None,
+        }
+    }
+
+    /// El tono no está definido (grises)
+    fn is_achromatic(self, [_, b, c]: [f32; 3]) -> bool {
+        match self {
+            Self::Hsl | Self::Hsv => b < 1e-4,
+            Self::Hwb => b + c >= 1.0 - 1e-4,
+            Self::Lch => b < 1e-2,
+            Self::Oklch => b < 1e-4,
+            _ => false,
+        }
+    }
+}
+
+// Blanco de referencia D65 para XYZ con Y = 1.0
+const WHITE_D65: [f32; 3] = [0.950_47, 1.0, 1.088_83];
+const LAB_EPSILON: f32 = 216.0 / 24389.0;
+const LAB_KAPPA: f32 = 24389.0 / 27.0;
+
+/// Canal sRGB (0.0 .. 1.0) a luz lineal
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Luz lineal (0.0 .. 1.0) a canal sRGB
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Conversiones entre espacios de color.
+///
+/// Rangos: tonos en grados 0.0 .. 360.0; s, v, l, w, b de HSL/HSV/HWB en 0.0 .. 1.0;
+/// RGB lineal y XYZ en 0.0 .. 1.0; Lab con L en 0.0 .. 100.0; OKLab con L en 0.0 .. 1.0.
+/// Los constructores recortan los colores fuera de gamut y crean colores opacos.
+impl Color {
+    /// Inversa de `Color::hsl`
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let [r, g, b] = self.unit_rgb();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        (hue_of(r, g, b, max, delta), s.min(1.0), l)
+    }
+
+    pub fn hsv(h: f32, s: f32, v: f32) -> Self {
+        let s = s.clamp(0.0, 1.0);
+        let v = v.clamp(0.0, 1.0);
+        let h = h.rem_euclid(360.0) / 60.0;
+        let c = v * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let m = v - c;
+        Self::from_unit_rgb([r + m, g + m, b + m])
+    }
+
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let [r, g, b] = self.unit_rgb();
+        let max = r.max(g).max(b);
+        let delta = max - r.min(g).min(b);
+        if delta == 0.0 {
+            return (0.0, 0.0, max);
+        }
+        (hue_of(r, g, b, max, delta), delta / max, max)
+    }
+
+    /// Tono, blancura y negrura
+    pub fn hwb(h: f32, w: f32, b: f32) -> Self {
+        let (w, b) = (w.clamp(0.0, 1.0), b.clamp(0.0, 1.0));
+        if w + b >= 1.0 {
+            let gray = w / (w + b);
+            return Self::from_unit_rgb([gray; 3]);
+        }
+        let v = 1.0 - b;
+        Self::hsv(h, 1.0 - w / v, v)
+    }
+
+    pub fn to_hwb(&self) -> (f32, f32, f32) {
+        let (h, s, v) = self.to_hsv();
+        (h, (1.0 - s) * v, 1.0 - v)
+    }
+
+    /// Canales en sRGB lineal
+    pub fn from_linear(r: f32, g: f32, b: f32) -> Self {
+        Self::from_unit_rgb([r, g, b].map(linear_to_srgb))
+    }
+
+    pub fn to_linear(&self) -> (f32, f32, f32) {
+        let [r, g, b] = self.unit_rgb().map(srgb_to_linear);
+        (r, g, b)
+    }
+
+    pub fn from_xyz(x: f32, y: f32, z: f32) -> Self {
+        let r = 3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z;
+        let g = -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z;
+        let b = 0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z;
+        Self::from_linear(r, g, b)
+    }
+
+    pub fn to_xyz(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_linear();
+        (
+            0.412_456_4 * r + 0.357_576_1 * g + 0.180_437_5 * b,
+            0.212_672_9 * r + 0.715_152_2 * g + 0.072_175 * b,
+            0.019_333_9 * r + 0.119_192 * g + 0.950_304_1 * b,
+        )
+    }
+
+    /// CIE L*a*b*
+    pub fn lab(l: f32, a: f32, b: f32) -> Self {
+        let fy = (l + 16.0) / 116.0;
+        let fx = fy + a / 500.0;
+        let fz = fy - b / 200.0;
+
+        let inverse = |f: f32| {
+            let cube = f * f * f;
+            if cube > LAB_EPSILON {
+                cube
+            } else {
+                (116.0 * f - 16.0) / LAB_KAPPA
+            }
+        };
+        let yr = if l > LAB_KAPPA * LAB_EPSILON {
+            fy * fy * fy
+        } else {
+            l / LAB_KAPPA
+        };
+
+        let [wx, wy, wz] = WHITE_D65;
+        Self::from_xyz(inverse(fx) * wx, yr * wy, inverse(fz) * wz)
+    }
+
+    pub fn to_lab(&self) -> (f32, f32, f32) {
+        let (x, y, z) = self.to_xyz();
+        let [wx, wy, wz] = WHITE_D65;
+        let f = |t: f32| {
+            if t > LAB_EPSILON {
+                t.cbrt()
+            } else {
+                (LAB_KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(x / wx), f(y / wy), f(z / wz));
+        (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+    }
+
+    /// CIE LCh: luminosidad, croma y tono
+    pub fn lch(l: f32, c: f32, h: f32) -> Self {
+        let (a, b) = from_polar(c, h);
+        Self::lab(l, a, b)
+    }
+
+    pub fn to_lch(&self) -> (f32, f32, f32) {
+        let (l, a, b) = self.to_lab();
+        let (c, h) = to_polar(a, b);
+        (l, c, h)
+    }
+
+    pub fn oklab(l: f32, a: f32, b: f32) -> Self {
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+        Self::from_linear(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        )
+    }
+
+    pub fn to_oklab(&self) -> (f32, f32, f32) {
+        let (r, g, b) = self.to_linear();
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        (
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+
+    pub fn oklch(l: f32, c: f32, h: f32) -> Self {
+        let (a, b) = from_polar(c, h);
+        Self::oklab(l, a, b)
+    }
+
+    pub fn to_oklch(&self) -> (f32, f32, f32) {
+        let (l, a, b) = self.to_oklab();
+        let (c, h) = to_polar(a, b);
+        (l, c, h)
+    }
+
+    /// Componentes en `space`, en el orden de sus nombres (p. ej. Lch → [l, c, h])
+    pub fn to_space(&self, space: ColorSpace) -> [f32; 3] {
+        let (a, b, c) = match space {
+            ColorSpace::Srgb => {
+                let [r, g, b] = self.unit_rgb();
+                (r, g, b)
+            }
+            ColorSpace::LinearRgb => self.to_linear(),
+            ColorSpace::Hsl => self.to_hsl(),
+            ColorSpace::Hsv => self.to_hsv(),
+            ColorSpace::Hwb => self.to_hwb(),
+            ColorSpace::Xyz => self.to_xyz(),
+            ColorSpace::Lab => self.to_lab(),
+            ColorSpace::Lch => self.to_lch(),
+            ColorSpace::Oklab => self.to_oklab(),
+            ColorSpace::Oklch => self.to_oklch(),
+        };
+        [a, b, c]
+    }
+
+    /// Inversa de `to_space`; el color resultante es opaco
+    pub fn from_space(space: ColorSpace, [a, b, c]: [f32; 3]) -> Self {
+        match space {
+            ColorSpace::Srgb => Self::from_unit_rgb([a, b, c]),
+            ColorSpace::LinearRgb => Self::from_linear(a, b, c),
+            ColorSpace::Hsl => Self::hsl(a, b, c),
+            ColorSpace::Hsv => Self::hsv(a, b, c),
+            ColorSpace::Hwb => Self::hwb(a, b, c),
+            ColorSpace::Xyz => Self::from_xyz(a, b, c),
+            ColorSpace::Lab => Self::lab(a, b, c),
+            ColorSpace::Lch => Self::lch(a, b, c),
+            ColorSpace::Oklab => Self::oklab(a, b, c),
+            ColorSpace::Oklch => Self::oklch(a, b, c),
+        }
+    }
+
+    /// Como `Color::lerp`, pero interpolando en `space`.
+    /// En espacios polares el tono recorre el arco más corto.
+    pub fn lerp_in(c1: Color, c2: Color, t: f32, space: ColorSpace) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        if space == ColorSpace::Srgb {
+            return Self::lerp(c1, c2, t);
+        }
+
+        let mut from = c1.to_space(space);
+        let mut to = c2.to_space(space);
+
+        if let Some(hue) = space.hue_index() {
+            // Un gris no tiene tono: se toma el del otro color para no pasar por otros tonos
+            match (space.is_achromatic(from), space.is_achromatic(to)) {
+                (true, false) => from[hue] = to[hue],
+                (false, true) => to[hue] = from[hue],
+                _ => {}
+            }
+            let delta = (to[hue] - from[hue] + 180.0).rem_euclid(360.0) - 180.0;
+            to[hue] = from[hue] + delta;
+        }
+
+        let mixed = std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t);
+        let mut color = Self::from_space(space, mixed);
+        color.a = (c1.a as f32 + (c2.a as f32 - c1.a as f32) * t).round() as u8;
+        color
+    }
+
+    /// Empieza en `&self` y termina con `end`, interpolando en `space`
+    pub fn interpolate_color_in(&self, end: Self, t: f32, space: ColorSpace) -> Self {
+        Self::lerp_in(*self, end, t, space)
+    }
+
+    fn unit_rgb(&self) -> [f32; 3] {
+        [self.r, self.g, self.b].map(|value| value as f32 / 255.0)
+    }
+
+    pub(crate) fn from_unit_rgb(rgb: [f32; 3]) -> Self {
+        let [r, g, b] = rgb.map(|value| (value.clamp(0.0, 1.0) * 255.0).round() as u8);
+        Self::rgb(r, g, b)
+    }
+}
+
+fn hue_of(r: f32, g: f32, b: f32, max: f32, delta: f32) -> f32 {
+    let hue = if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    hue.rem_euclid(360.0)
+}
+
+fn to_polar(a: f32, b: f32) -> (f32, f32) {
+    (
+        (a * a + b * b).sqrt(),
+        b.atan2(a).to_degrees().rem_euclid(360.0),
+    )
+}
+
+pub(crate) fn from_polar(c: f32, h: f32) -> (f32, f32) {
+    let (sin, cos) = h.to_radians().sin_cos();
+    (c * cos, c * sin)
+}
@@ -1,3 +1,7 @@
+use rand::Rng;
+
+use super::random::with_rng;
+
 
 #[derive(Debug, Clone, Copy)]
 pub struct Color {
@@ -27,18 +31,26 @@ impl Color {
         Self { r, g, b, a }
     }
 
+    /// Color opaco al azar con el generador del hilo (sembrado por `Galar`)
     pub fn random() -> Self {
-        let r = rand::random_range(0..255);
-        let g = rand::random_range(0..255);
-        let b = rand::random_range(0..255);
+        with_rng(Self::random_with)
+    }
+    pub fn random_with_alpha() -> Self {
+        with_rng(Self::random_with_alpha_with)
+    }
+    /// Color opaco al azar con un generador explícito, p. ej. `config.rng()`
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let r = rng.random_range(0..255);
+        let g = rng.random_range(0..255);
+        let b = rng.random_range(0..255);
         let a = 255;
         Self { r, g, b, a }
     }
-    pub fn random_with_alpha() -> Self {
-        let r = rand::random_range(0..255);
-        let g = rand::random_range(0..255);
-        let b = rand::random_range(0..255);
-        let a = rand::random_range(0..255);
+    pub fn random_with_alpha_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let r = rng.random_range(0..255);
+        let g = rng.random_range(0..255);
+        let b = rng.random_range(0..255);
+        let a = rng.random_range(0..255);
         Self { r, g, b, a }
     }
     /// Valores en un rango de:  
@@ -1,9 +1,11 @@
+use glam::{Affine2, Vec2};
+
 use super::{
+    canvas::Canvas,
     colores::Color,
+    paint::PaintSpace,
     shape::{GeometryShape, Shape, Vertex},
-    texture::Material,
-    transform::Transform,
-    utils::ConfigGalar,
+    texture::{Material, Texture, tint_pixel},
 };
 
 pub fn draw_circle(x: f32, y: f32, radio: f32, segments: usize, color: Color) -> GeometryShape {
@@ -24,42 +26,67 @@ pub fn draw_triangle(x: f32, y: f32, size: f32, color: Color) -> GeometryShape {
     GeometryShape::Triangle { x, y, size, color }
 }
 
-pub fn draw_shape(config: &mut ConfigGalar, shape: &Shape) {
+/// Dibuja el shape en la ventana (`ConfigGalar`) o en cualquier otro `Canvas`
+pub fn draw_shape<C: Canvas + ?Sized>(canvas: &mut C, shape: &Shape) {
+    draw_shape_with_matrix(canvas, shape, shape.model_matrix());
+}
+
+/// Dibuja el shape con una matriz de modelo explícita (p. ej. la del mundo en un grafo de escena).
+/// La vista de la cámara se aplica después de `model`.
+pub fn draw_shape_with_matrix<C: Canvas + ?Sized>(canvas: &mut C, shape: &Shape, model: Affine2) {
+    // Todos los vértices se transforman una sola vez con la matriz precalculada
+    let mut points: Vec<Vec2> = shape.vertices.iter().map(|v| Vec2::new(v.x, v.y)).collect();
+    for point in points.iter_mut() {
+        *point = model.transform_point2(*point);
+    }
+
+    // Redondeo para evitar desplazamientos de medio píxel
+    if shape.origen {
+        for point in points.iter_mut() {
+            *point = point.round();
+        }
+    }
+
+    // Vista de la cámara, después del Transform del shape
+    if let Some(camera) = canvas.camera().filter(|camera| !camera.is_identity()) {
+        let view = camera.view_matrix();
+        for point in points.iter_mut() {
+            *point = view.transform_point2(*point);
+        }
+    }
+
+    // La textura se bloquea una vez por shape, no por píxel
+    let texture = shape.material.texture.as_ref().map(|texture| texture.read());
+
     for triangle in &shape.indices {
         let v0 = &shape.vertices[triangle[0]];
         let v1 = &shape.vertices[triangle[1]];
         let v2 = &shape.vertices[triangle[2]];
 
-        draw_triangle_cpu(config, shape, &shape.transform, v0, v1, v2, &shape.material);
+        let p0 = points[triangle[0]].into();
+        let p1 = points[triangle[1]].into();
+        let p2 = points[triangle[2]].into();
+
+        draw_triangle_cpu(canvas, (p0, p1, p2), v0, v1, v2, &shape.material, texture.as_deref());
     }
 }
 
-fn draw_triangle_cpu(
-    config: &mut ConfigGalar,
-    shape: &Shape,
-    transform: &Transform,
+fn draw_triangle_cpu<C: Canvas + ?Sized>(
+    canvas: &mut C,
+    (p0, p1, p2): ((f32, f32), (f32, f32), (f32, f32)),
     v0: &Vertex,
     v1: &Vertex,
     v2: &Vertex,
     material: &Material,
+    texture: Option<&Texture>,
 ) {
-    let (width, _) = config.size();
+    let (width, height) = canvas.size();
 
-    let p0: (f32, f32);
-    let p1: (f32, f32);
-    let p2: (f32, f32);
-    if shape.origen {
-        // Dimensiones aproximadas del shape (puede venir de otra parte)
-        let (center_x, center_y) = shape.get_center();
-
-        // Aplicamos transformación centrada
-        p0 = transform.apply_centered(v0.x, v0.y, center_x, center_y);
-        p1 = transform.apply_centered(v1.x, v1.y, center_x, center_y);
-        p2 = transform.apply_centered(v2.x, v2.y, center_x, center_y);
-    } else {
-        p0 = transform.apply(v0.x, v0.y);
-        p1 = transform.apply(v1.x, v1.y);
-        p2 = transform.apply(v2.x, v2.y);
+    // Triángulo completamente fuera de pantalla
+    let out_x = p0.0.max(p1.0).max(p2.0) < 0.0 || p0.0.min(p1.0).min(p2.0) > width as f32 - 1.0;
+    let out_y = p0.1.max(p1.1).max(p2.1) < 0.0 || p0.1.min(p1.1).min(p2.1) > height as f32 - 1.0;
+    if out_x || out_y {
+        return;
     }
 
     // bounding box con clamp
@@ -67,7 +94,7 @@ fn draw_triangle_cpu(
     let max_x = p0.0.max(p1.0).max(p2.0).trunc().min(width as f32 - 1.0) as usize;
 
     let min_y = p0.1.min(p1.1).min(p2.1).trunc().max(0.0) as usize;
-    let max_y = p0.1.max(p1.1).max(p2.1).trunc().min(width as f32 - 1.0) as usize;
+    let max_y = p0.1.max(p1.1).max(p2.1).trunc().min(height as f32 - 1.0) as usize;
 
     let area = edge_function_f32(p0, p1, p2);
 
@@ -87,7 +114,6 @@ fn draw_triangle_cpu(
         let w2 = edge_function_f32((px, py), p0, p1) / area;
 
         if w0 >= 0.0 && w1 >= 0.0 && w2 >= 0.0 {
-            let index = y * width + x;
             let color = if let Some(color) = material.base_color {
                 color.to_hex()
             } else {
@@ -103,7 +129,7 @@ fn draw_triangle_cpu(
 
                 Color::rgb(r, g, b).to_hex()
             };
-            if let Some(_) = material.texture {
+            if let Some(texture) = texture {
                 let uv_0 = v0.uv;
                 let uv_1 = v1.uv;
                 let uv_2 = v2.uv;
@@ -111,9 +137,27 @@ fn draw_triangle_cpu(
                     w0 * uv_0.x + w1 * uv_1.x + w2 * uv_2.x,
                     w0 * uv_0.y + w1 * uv_1.y + w2 * uv_2.y,
                 );
-                config.explicit_draw(index, material.sample_texture(uv));
+                let mut texel = texture.sample(uv);
+                if let Some(tint) = material.tint {
+                    texel = tint_pixel(texel, tint);
+                }
+
+                // Los texels transparentes no se dibujan (recortes de sprites) y los
+                // semitransparentes se mezclan en luz lineal
+                canvas.blend_pixel(x, y, texel);
+            } else if let Some(paint) = &material.paint {
+                // En espacio local se interpola la posición del vértice, así el
+                // degradado sigue al Transform y a la cámara sin invertir matrices
+                let point = match paint.space {
+                    PaintSpace::Local => Vec2::new(
+                        w0 * v0.x + w1 * v1.x + w2 * v2.x,
+                        w0 * v0.y + w1 * v1.y + w2 * v2.y,
+                    ),
+                    PaintSpace::Screen => Vec2::new(px, py),
+                };
+                canvas.blend_pixel(x, y, paint.color_at(point));
             } else {
-                config.explicit_draw(index, color);
+                canvas.blend_pixel(x, y, color);
             }
         }
     }
@@ -0,0 +1,398 @@
+use rand::{Rng, RngCore, SeedableRng, rngs::StdRng};
+
+use super::{
+    postprocess::{PostEffect, gaussian_weights, luminance, pack_rgb, separable, unpack_rgb},
+    random::with_rng,
+};
+
+/// Resplandor: las zonas más brillantes que `threshold` se desenfocan a menor
+/// resolución y se suman sobre la imagen.
+#[derive(Debug, Clone)]
+pub struct Bloom {
+    /// Luminancia mínima (0.0 .. 1.0) que empieza a brillar
+    pub threshold: f32,
+    /// Cuánto del resplandor se suma
+    pub intensity: f32,
+    /// Desenfoque, en píxeles de la imagen reducida
+    pub sigma: f32,
+    /// Factor de reducción antes de desenfocar (1 = resolución completa)
+    pub downsample: usize,
+    bright: Vec<u32>,
+    blurred: Vec<u32>,
+    scratch: Vec<u32>,
+    weights: Vec<f32>,
+    cached_sigma: f32,
+}
+
+impl Bloom {
+    pub fn new(threshold: f32, intensity: f32) -> Self {
+        Self {
+            threshold,
+            intensity,
+            sigma: 2.0,
+            downsample: 4,
+            bright: Vec::new(),
+            blurred: Vec::new(),
+            scratch: Vec::new(),
+            weights: Vec::new(),
+            cached_sigma: 0.0,
+        }
+    }
+
+    pub fn with_sigma(mut self, sigma: f32) -> Self {
+        self.sigma = sigma;
+        self
+    }
+
+    pub fn with_downsample(mut self, downsample: usize) -> Self {
+        self.downsample = downsample;
+        self
+    }
+}
+
+impl Default for Bloom {
+    fn default() -> Self {
+        Self::new(0.7, 1.0)
+    }
+}
+
+impl PostEffect for Bloom {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        let factor = self.downsample.max(1);
+        let (small_w, small_h) = (width.div_ceil(factor), height.div_ceil(factor));
+        self.bright.resize(small_w * small_h, 0);
+        self.blurred.resize(small_w * small_h, 0);
+
+        // Paso de brillo con rodilla suave, promediando cada bloque
+        let threshold = self.threshold.clamp(0.0, 1.0) * 255.0;
+        let knee = (255.0 - threshold).max(1.0);
+        for sy in 0..small_h {
+            for sx in 0..small_w {
+                let mut sum = [0.0f32; 3];
+                let mut count = 0.0;
+                for y in sy * factor..((sy + 1) * factor).min(height) {
+                    for x in sx * factor..((sx + 1) * factor).min(width) {
+                        let pixel = src[y * width + x];
+                        let weight = ((luminance(pixel) - threshold) / knee).clamp(0.0, 1.0);
+                        for (acc, value) in sum.iter_mut().zip(unpack_rgb(pixel)) {
+                            *acc += value * weight;
+                        }
+                        count += 1.0;
+                    }
+                }
+                self.bright[sy * small_w + sx] = pack_rgb(0, sum.map(|value| value / count));
+            }
+        }
+
+        if self.sigma > 0.0 {
+            if self.weights.is_empty() || self.cached_sigma != self.sigma {
+                gaussian_weights(self.sigma, &mut self.weights);
+                self.cached_sigma = self.sigma;
+            }
+            separable(
+                &self.bright,
+                &mut self.blurred,
+                &mut self.scratch,
+                (small_w, small_h),
+                &self.weights,
+            );
+        } else {
+            self.blurred.copy_from_slice(&self.bright);
+        }
+
+        // Se vuelve a resolución completa con interpolación bilineal y se suma
+        for y in 0..height {
+            for x in 0..width {
+                let glow = sample_bilinear(
+                    &self.blurred,
+                    (small_w, small_h),
+                    (x as f32 + 0.5) / factor as f32 - 0.5,
+                    (y as f32 + 0.5) / factor as f32 - 0.5,
+                );
+                let index = y * width + x;
+                let mut out = unpack_rgb(src[index]);
+                for (value, glow) in out.iter_mut().zip(glow) {
+                    *value += glow * self.intensity;
+                }
+                dst[index] = pack_rgb(src[index], out);
+            }
+        }
+    }
+    fn name(&self) -> &str {
+        "Bloom"
+    }
+}
+
+/// Oscurece los bordes de la imagen
+#[derive(Debug, Clone, Copy)]
+pub struct Vignette {
+    /// Oscurecimiento máximo en las esquinas (0.0 .. 1.0)
+    pub strength: f32,
+    /// Distancia al centro (0.0 centro, 1.0 esquina) donde empieza a oscurecer
+    pub radius: f32,
+    /// Ancho de la transición
+    pub softness: f32,
+}
+
+impl Vignette {
+    pub fn new(strength: f32) -> Self {
+        Self {
+            strength,
+            radius: 0.5,
+            softness: 0.5,
+        }
+    }
+
+    pub fn with_radius(mut self, radius: f32, softness: f32) -> Self {
+        self.radius = radius;
+        self.softness = softness;
+        self
+    }
+}
+
+impl Default for Vignette {
+    fn default() -> Self {
+        Self::new(0.6)
+    }
+}
+
+impl PostEffect for Vignette {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        let (cx, cy) = (width as f32 / 2.0, height as f32 / 2.0);
+        let max_distance = (cx * cx + cy * cy).sqrt().max(1.0);
+
+        for y in 0..height {
+            for x in 0..width {
+                let (dx, dy) = (x as f32 + 0.5 - cx, y as f32 + 0.5 - cy);
+                let distance = (dx * dx + dy * dy).sqrt() / max_distance;
+                let t = smoothstep(self.radius, self.radius + self.softness, distance);
+                let factor = 1.0 - self.strength * t;
+
+                let index = y * width + x;
+                dst[index] = pack_rgb(src[index], unpack_rgb(src[index]).map(|value| value * factor));
+            }
+        }
+    }
+    fn name(&self) -> &str {
+        "Vignette"
+    }
+}
+
+/// Separa los canales rojo y azul hacia los bordes, como una lente barata
+#[derive(Debug, Clone, Copy)]
+pub struct ChromaticAberration {
+    /// Desplazamiento en píxeles en los bordes; crece desde 0 en el centro
+    pub offset: f32,
+}
+
+impl ChromaticAberration {
+    pub fn new(offset: f32) -> Self {
+        Self { offset }
+    }
+}
+
+impl Default for ChromaticAberration {
+    fn default() -> Self {
+        Self::new(3.0)
+    }
+}
+
+impl PostEffect for ChromaticAberration {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        let (cx, cy) = (width as f32 / 2.0, height as f32 / 2.0);
+
+        for y in 0..height {
+            for x in 0..width {
+                // Dirección radial normalizada: -1 .. 1 en cada eje
+                let dx = (x as f32 - cx) / cx.max(1.0) * self.offset;
+                let dy = (y as f32 - cy) / cy.max(1.0) * self.offset;
+
+                let red = sample_nearest(src, (width, height), x as f32 + dx, y as f32 + dy);
+                let blue = sample_nearest(src, (width, height), x as f32 - dx, y as f32 - dy);
+
+                let index = y * width + x;
+                dst[index] = (src[index] & 0xFF00_FF00) | (red & 0x00FF_0000) | (blue & 0x0000_00FF);
+            }
+        }
+    }
+    fn name(&self) -> &str {
+        "ChromaticAberration"
+    }
+}
+
+/// Ruido de película distinto en cada frame; sin `with_seed` se siembra desde `Galar`
+#[derive(Debug, Clone)]
+pub struct FilmGrain {
+    /// Amplitud del ruido (0.0 .. 1.0 de la escala de color)
+    pub intensity: f32,
+    /// El mismo ruido en los tres canales
+    pub monochrome: bool,
+    rng: StdRng,
+}
+
+impl FilmGrain {
+    pub fn new(intensity: f32) -> Self {
+        Self {
+            intensity,
+            monochrome: true,
+            rng: StdRng::seed_from_u64(with_rng(|rng| rng.next_u64())),
+        }
+    }
+
+    pub fn with_monochrome(mut self, monochrome: bool) -> Self {
+        self.monochrome = monochrome;
+        self
+    }
+
+    /// Secuencia de ruido reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+}
+
+impl Default for FilmGrain {
+    fn default() -> Self {
+        Self::new(0.08)
+    }
+}
+
+impl PostEffect for FilmGrain {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], _width: usize, _height: usize) {
+        let amplitude = self.intensity * 255.0;
+
+        for (out, &pixel) in dst.iter_mut().zip(src) {
+            let rgb = unpack_rgb(pixel);
+            let noisy = if self.monochrome {
+                let noise = self.rng.random_range(-1.0..=1.0) * amplitude;
+                rgb.map(|value| value + noise)
+            } else {
+                rgb.map(|value| value + self.rng.random_range(-1.0..=1.0) * amplitude)
+            };
+            *out = pack_rgb(pixel, noisy);
+        }
+    }
+    fn name(&self) -> &str {
+        "FilmGrain"
+    }
+}
+
+/// Simulación de monitor CRT: pantalla curva, líneas de barrido y máscara de fósforo
+#[derive(Debug, Clone, Copy)]
+pub struct Crt {
+    /// Curvatura del tubo, 0.0 es plano (valores útiles 0.05 .. 0.3)
+    pub curvature: f32,
+    /// Oscurecimiento de las filas impares (0.0 .. 1.0)
+    pub scanlines: f32,
+    /// Intensidad de la máscara RGB por columnas (0.0 .. 1.0)
+    pub mask: f32,
+    /// Color fuera del tubo
+    pub border: u32,
+}
+
+impl Crt {
+    pub fn new() -> Self {
+        Self {
+            curvature: 0.1,
+            scanlines: 0.35,
+            mask: 0.25,
+            border: 0xFF00_0000,
+        }
+    }
+
+    pub fn with_curvature(mut self, curvature: f32) -> Self {
+        self.curvature = curvature;
+        self
+    }
+
+    pub fn with_scanlines(mut self, scanlines: f32) -> Self {
+        self.scanlines = scanlines;
+        self
+    }
+
+    pub fn with_mask(mut self, mask: f32) -> Self {
+        self.mask = mask;
+        self
+    }
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PostEffect for Crt {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        let (w, h) = (width as f32, height as f32);
+
+        for y in 0..height {
+            for x in 0..width {
+                let index = y * width + x;
+
+                // Distorsión de barril en coordenadas -1 .. 1
+                let u = (x as f32 + 0.5) / w * 2.0 - 1.0;
+                let v = (y as f32 + 0.5) / h * 2.0 - 1.0;
+                let u2 = u * (1.0 + self.curvature * v * v);
+                let v2 = v * (1.0 + self.curvature * u * u);
+                if u2.abs() > 1.0 || v2.abs() > 1.0 {
+                    dst[index] = self.border;
+                    continue;
+                }
+
+                let sx = (u2 + 1.0) * 0.5 * w - 0.5;
+                let sy = (v2 + 1.0) * 0.5 * h - 0.5;
+                let pixel = sample_nearest(src, (width, height), sx, sy);
+                let mut rgb = unpack_rgb(pixel);
+
+                // Las líneas siguen las filas de la imagen curvada
+                if (sy.round() as isize).rem_euclid(2) == 1 {
+                    rgb = rgb.map(|value| value * (1.0 - self.scanlines));
+                }
+
+                // Tríadas de fósforo: cada columna deja pasar un canal
+                let lit = x % 3;
+                for (channel, value) in rgb.iter_mut().enumerate() {
+                    if channel != lit {
+                        *value *= 1.0 - self.mask;
+                    }
+                }
+
+                dst[index] = pack_rgb(pixel, rgb);
+            }
+        }
+    }
+    fn name(&self) -> &str {
+        "Crt"
+    }
+}
+
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    let t = ((x - edge0) / (edge1 - edge0).max(f32::EPSILON)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+fn sample_nearest(src: &[u32], (width, height): (usize, usize), x: f32, y: f32) -> u32 {
+    let x = (x.round() as isize).clamp(0, width as isize - 1) as usize;
+    let y = (y.round() as isize).clamp(0, height as isize - 1) as usize;
+    src[y * width + x]
+}
+
+fn sample_bilinear(src: &[u32], (width, height): (usize, usize), x: f32, y: f32) -> [f32; 3] {
+    let x = x.clamp(0.0, (width - 1) as f32);
+    let y = y.clamp(0.0, (height - 1) as f32);
+    let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+    let (x1, y1) = ((x0 + 1).min(width - 1), (y0 + 1).min(height - 1));
+    let (tx, ty) = (x - x0 as f32, y - y0 as f32);
+
+    let a = unpack_rgb(src[y0 * width + x0]);
+    let b = unpack_rgb(src[y0 * width + x1]);
+    let c = unpack_rgb(src[y1 * width + x0]);
+    let d = unpack_rgb(src[y1 * width + x1]);
+
+    std::array::from_fn(|channel| {
+        let top = a[channel] + (b[channel] - a[channel]) * tx;
+        let bottom = c[channel] + (d[channel] - c[channel]) * tx;
+        top + (bottom - top) * ty
+    })
+}
@@ -0,0 +1,281 @@
+use super::{color_space::ColorSpace, colores::Color};
+
+/// Parada de color en `offset` (0.0 .. 1.0)
+#[derive(Debug, Clone, Copy)]
+pub struct ColorStop {
+    pub offset: f32,
+    pub color: Color,
+}
+
+impl ColorStop {
+    pub fn new(offset: f32, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// Qué pasa con `t` fuera de 0.0 .. 1.0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Spread {
+    /// Se extienden los colores de los extremos
+    #[default]
+    Pad,
+    /// El degradado se repite
+    Repeat,
+    /// El degradado se repite alternando el sentido
+    Mirror,
+}
+
+impl Spread {
+    /// Lleva `t` a 0.0 .. 1.0
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Pad => t.clamp(0.0, 1.0),
+            Self::Repeat => t.rem_euclid(1.0),
+            Self::Mirror => 1.0 - (t.rem_euclid(2.0) - 1.0).abs(),
+        }
+    }
+}
+
+/// Degradado con paradas de color arbitrarias
+#[derive(Debug, Clone)]
+pub struct Gradient {
+    stops: Vec<ColorStop>,
+    pub space: ColorSpace,
+    pub spread: Spread,
+}
+
+impl Gradient {
+    /// Colores repartidos uniformemente de 0.0 a 1.0
+    pub fn new(colors: &[Color]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self::from_stops(
+            colors
+                .iter()
+                .enumerate()
+                .map(|(i, &color)| ColorStop::new(i as f32 / last, color))
+                .collect(),
+        )
+    }
+
+    /// Las paradas se ordenan por `offset`
+    pub fn from_stops(mut stops: Vec<ColorStop>) -> Self {
+        stops.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+        Self {
+            stops,
+            space: ColorSpace::Oklab,
+            spread: Spread::Pad,
+        }
+    }
+
+    /// Espacio de interpolación entre paradas (OKLab por defecto)
+    pub fn with_space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    pub fn with_spread(mut self, spread: Spread) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    pub fn with_stop(mut self, offset: f32, color: Color) -> Self {
+        self.add_stop(offset, color);
+        self
+    }
+
+    pub fn add_stop(&mut self, offset: f32, color: Color) {
+        let index = self.stops.partition_point(|stop| stop.offset <= offset);
+        self.stops.insert(index, ColorStop::new(offset, color));
+    }
+
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+
+    /// Color en `t`, aplicando `spread`
+    pub fn sample(&self, t: f32) -> Color {
+        self.sample_clamped(self.spread.apply(t))
+    }
+
+    /// Color en `t` ya dentro de 0.0 .. 1.0, sin aplicar `spread`
+    fn sample_clamped(&self, t: f32) -> Color {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return Color::default();
+        };
+
+        if t <= first.offset {
+            return first.color;
+        }
+        if t >= last.offset {
+            return last.color;
+        }
+
+        let index = self.stops.partition_point(|stop| stop.offset <= t);
+        let (from, to) = (self.stops[index - 1], self.stops[index]);
+        let span = to.offset - from.offset;
+        if span <= f32::EPSILON {
+            return to.color;
+        }
+        Color::lerp_in(from.color, to.color, (t - from.offset) / span, self.space)
+    }
+
+    /// `size` colores muestreados de 0.0 a 1.0, para consultas rápidas por píxel.
+    /// No aplica `spread`: con `Repeat` el último color sigue siendo la última parada
+    pub fn lookup_table(&self, size: usize) -> Vec<Color> {
+        let last = size.saturating_sub(1).max(1) as f32;
+        (0..size)
+            .map(|i| self.sample_clamped(i as f32 / last))
+            .collect()
+    }
+}
+
+impl From<Colormap> for Gradient {
+    fn from(colormap: Colormap) -> Self {
+        colormap.gradient(16)
+    }
+}
+
+/// Mapas de color científicos, perceptualmente uniformes (salvo turbo)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colormap {
+    Viridis,
+    Magma,
+    Inferno,
+    Plasma,
+    /// Pensado para personas con visión de color deficiente
+    Cividis,
+    /// Arcoíris mejorado de Google, sin bandas falsas; no es perceptualmente uniforme
+    Turbo,
+}
+
+impl Colormap {
+    pub const ALL: [Colormap; 6] = [
+        Self::Viridis,
+        Self::Magma,
+        Self::Inferno,
+        Self::Plasma,
+        Self::Cividis,
+        Self::Turbo,
+    ];
+
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Viridis => "viridis",
+            Self::Magma => "magma",
+            Self::Inferno => "inferno",
+            Self::Plasma => "plasma",
+            Self::Cividis => "cividis",
+            Self::Turbo => "turbo",
+        }
+    }
+
+    /// Color en `t` (0.0 .. 1.0, se recorta)
+    pub fn sample(&self, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let rgb = match self {
+            Self::Viridis => polynomial(&VIRIDIS, t),
+            Self::Magma => polynomial(&MAGMA, t),
+            Self::Inferno => polynomial(&INFERNO, t),
+            Self::Plasma => polynomial(&PLASMA, t),
+            Self::Turbo => turbo(t),
+            Self::Cividis => return cividis().sample(t),
+        };
+        let [r, g, b] = rgb.map(|value| (value.clamp(0.0, 1.0) * 255.0).round() as u8);
+        Color::rgb(r, g, b)
+    }
+
+    /// Gradiente con `stops` muestras del mapa
+    pub fn gradient(&self, stops: usize) -> Gradient {
+        let last = stops.saturating_sub(1).max(1) as f32;
+        Gradient::from_stops(
+            (0..stops.max(2))
+                .map(|i| {
+                    let t = i as f32 / last;
+                    ColorStop::new(t, self.sample(t))
+                })
+                .collect(),
+        )
+    }
+}
+
+// Ajustes polinómicos de grado 6 de los mapas de matplotlib (Matt Zucker, CC0)
+type Coefficients = [[f32; 3]; 7];
+
+const VIRIDIS: Coefficients = [
+    [0.277_727_33, 0.005_407_344_5, 0.334_099_8],
+    [0.105_093_04, 1.404_613_5, 1.384_590_2],
+    [-0.330_861_83, 0.214_847_56, 0.095_095_165],
+    [-4.634_230_6, -5.799_101, -19.332_441],
+    [6.228_27, 14.179_934, 56.690_55],
+    [4.776_385, -13.745_146, -65.353_03],
+    [-5.435_456, 4.645_852_6, 26.312_435],
+];
+
+const MAGMA: Coefficients = [
+    [-0.002_136_485, -0.000_749_655_05, -0.005_386_128],
+    [0.251_660_54, 0.677_523_2, 2.494_026_6],
+    [8.353_717, -3.577_719_4, 0.314_467_9],
+    [-27.668_733, 14.264_731, -13.649_213],
+    [52.176_14, -27.943_607, 12.944_169],
+    [-50.768_524, 29.046_583, 4.234_153],
+    [18.655_705, -11.489_773, -5.601_961_6],
+];
+
+const INFERNO: Coefficients = [
+    [0.000_218_940_37, 0.001_651_004_6, -0.019_480_899],
+    [0.106_513_42, 0.563_956_44, 3.932_712_3],
+    [11.602_493, -3.972_854, -15.942_394],
+    [-41.703_995, 17.436_398, 44.354_145],
+    [77.162_94, -33.402_36, -81.807_31],
+    [-71.319_43, 32.626_064, 73.209_52],
+    [25.131_126, -12.242_669, -23.070_325],
+];
+
+const PLASMA: Coefficients = [
+    [0.058_732_344, 0.023_336_708, 0.543_340_2],
+    [2.176_514_6, 0.238_383_42, 0.753_960_45],
+    [-2.689_460_5, -7.455_851, 3.110_8],
+    [6.130_348, 42.346_19, -28.518_854],
+    [-11.107_436, -82.666_31, 60.139_847],
+    [10.023_066, 71.413_62, -54.072_186],
+    [-3.658_714, -22.931_534, 18.191_908],
+];
+
+fn polynomial(coefficients: &Coefficients, t: f32) -> [f32; 3] {
+    // Horner desde el término de mayor grado
+    let mut rgb = [0.0f32; 3];
+    for coefficient in coefficients.iter().rev() {
+        for (value, c) in rgb.iter_mut().zip(coefficient) {
+            *value = *value * t + c;
+        }
+    }
+    rgb
+}
+
+// Aproximación polinómica de Turbo (Anton Mikhailov, Google, Apache 2.0)
+fn turbo(t: f32) -> [f32; 3] {
+    let (t2, t3) = (t * t, t * t * t);
+    let (t4, t5) = (t3 * t, t3 * t2);
+    [
+        0.135_721_38 + 4.615_392_6 * t - 42.660_324 * t2 + 132.131_08 * t3 - 152.942_4 * t4
+            + 59.286_38 * t5,
+        0.091_402_61 + 2.194_188_4 * t + 4.842_966_6 * t2 - 14.185_033 * t3
+            + 4.277_298_6 * t4
+            + 2.829_566 * t5,
+        0.106_673_3 + 12.641_946 * t - 60.582_05 * t2 + 110.362_77 * t3 - 89.903_11 * t4
+            + 27.348_25 * t5,
+    ]
+}
+
+// Cividis muestreado en 10 paradas, interpolado en sRGB lineal
+fn cividis() -> &'static Gradient {
+    static CIVIDIS: std::sync::OnceLock<Gradient> = std::sync::OnceLock::new();
+    CIVIDIS.get_or_init(|| {
+        let colors = [
+            0x00224E, 0x123570, 0x3B496C, 0x575D6D, 0x707173, 0x8A8779, 0xA69D75, 0xC4B56C,
+            0xE4CF5B, 0xFEE838,
+        ]
+        .map(|hex: u32| Color::rgb((hex >> 16) as u8, (hex >> 8) as u8, hex as u8));
+        Gradient::new(&colors).with_space(ColorSpace::LinearRgb)
+    })
+}
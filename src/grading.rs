@@ -0,0 +1,509 @@
+use super::{
+    color_space::{linear_to_srgb, srgb_to_linear},
+    colores::Color,
+    postprocess::{PostEffect, pack_rgb, unpack_rgb},
+};
+
+/// LUT 3D de color, en el formato `.cube` de Adobe.
+/// Los valores van de `domain_min` a `domain_max` (normalmente 0.0 .. 1.0).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lut3D {
+    pub title: String,
+    size: usize,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+    // Rojo cambia más rápido: índice = r + g * size + b * size²
+    table: Vec<[f32; 3]>,
+}
+
+impl Lut3D {
+    /// LUT que no cambia los colores
+    pub fn identity(size: usize) -> Self {
+        let size = size.max(2);
+        let step = 1.0 / (size - 1) as f32;
+        let mut table = Vec::with_capacity(size * size * size);
+        for b in 0..size {
+            for g in 0..size {
+                for r in 0..size {
+                    table.push([r as f32 * step, g as f32 * step, b as f32 * step]);
+                }
+            }
+        }
+        Self {
+            title: String::new(),
+            size,
+            domain_min: [0.0; 3],
+            domain_max: [1.0; 3],
+            table,
+        }
+    }
+
+    /// Lee un archivo `.cube`
+    pub fn load(path: &str) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| format!("Error loading LUT {}: {}", path, e))?;
+        Self::parse(&text).map_err(|e| format!("Error parsing LUT {}: {}", path, e))
+    }
+
+    /// Interpreta el contenido de un archivo `.cube`
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut title = String::new();
+        let mut size = None;
+        let mut domain_min = [0.0; 3];
+        let mut domain_max = [1.0; 3];
+        let mut table = Vec::new();
+
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut words = line.split_whitespace();
+            let keyword = words.next().unwrap_or_default();
+            match keyword {
+                "TITLE" => title = line["TITLE".len()..].trim().trim_matches('"').to_string(),
+                "LUT_3D_SIZE" => {
+                    let value = words
+                        .next()
+                        .and_then(|word| word.parse::<usize>().ok())
+                        .filter(|&value| value >= 2)
+                        .ok_or_else(|| format!("line {}: invalid LUT_3D_SIZE", number + 1))?;
+                    size = Some(value);
+                }
+                "DOMAIN_MIN" => domain_min = parse_triplet(words, number)?,
+                "DOMAIN_MAX" => domain_max = parse_triplet(words, number)?,
+                "LUT_1D_SIZE" => return Err("1D LUTs are not supported".to_string()),
+                "LUT_3D_INPUT_RANGE" => {
+                    let [min, max, _] = parse_triplet(words.chain(["0"]), number)?;
+                    domain_min = [min; 3];
+                    domain_max = [max; 3];
+                }
+                _ if keyword.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '.') => {
+                    table.push(parse_triplet(line.split_whitespace(), number)?);
+                }
+                // Palabras clave desconocidas se ignoran, como hacen la mayoría de lectores
+                _ => {}
+            }
+        }
+
+        let size = size.ok_or("missing LUT_3D_SIZE")?;
+        if table.len() != size * size * size {
+            return Err(format!(
+                "expected {} entries for size {}, found {}",
+                size * size * size,
+                size,
+                table.len()
+            ));
+        }
+
+        Ok(Self {
+            title,
+            size,
+            domain_min,
+            domain_max,
+            table,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Color de salida para `rgb` (dentro del dominio), con interpolación trilineal
+    pub fn sample(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let last = (self.size - 1) as f32;
+        let mut base = [0usize; 3];
+        let mut frac = [0.0f32; 3];
+        for axis in 0..3 {
+            let range = (self.domain_max[axis] - self.domain_min[axis]).max(f32::EPSILON);
+            let position = ((rgb[axis] - self.domain_min[axis]) / range).clamp(0.0, 1.0) * last;
+            base[axis] = (position.floor() as usize).min(self.size - 2);
+            frac[axis] = position - base[axis] as f32;
+        }
+
+        let at = |r: usize, g: usize, b: usize| self.table[r + g * self.size + b * self.size * self.size];
+        let [r, g, b] = base;
+        let [fr, fg, fb] = frac;
+
+        let lerp = |a: [f32; 3], c: [f32; 3], t: f32| std::array::from_fn(|i| a[i] + (c[i] - a[i]) * t);
+        let c00 = lerp(at(r, g, b), at(r + 1, g, b), fr);
+        let c10 = lerp(at(r, g + 1, b), at(r + 1, g + 1, b), fr);
+        let c01 = lerp(at(r, g, b + 1), at(r + 1, g, b + 1), fr);
+        let c11 = lerp(at(r, g + 1, b + 1), at(r + 1, g + 1, b + 1), fr);
+        let c0 = lerp(c00, c10, fg);
+        let c1 = lerp(c01, c11, fg);
+        lerp(c0, c1, fb)
+    }
+
+    pub fn apply_color(&self, color: Color) -> Color {
+        let [r, g, b] = self.sample([color.r, color.g, color.b].map(|value| value as f32 / 255.0));
+        let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Color::rgba(channel(r), channel(g), channel(b), color.a)
+    }
+}
+
+fn parse_triplet<'a>(
+    mut words: impl Iterator<Item = &'a str>,
+    number: usize,
+) -> Result<[f32; 3], String> {
+    let mut values = [0.0; 3];
+    for value in values.iter_mut() {
+        *value = words
+            .next()
+            .and_then(|word| word.parse().ok())
+            .ok_or_else(|| format!("line {}: expected three numbers", number + 1))?;
+    }
+    Ok(values)
+}
+
+/// Etapa de post-proceso que pasa el frame por una LUT
+#[derive(Debug, Clone)]
+pub struct LutGrade {
+    pub lut: Lut3D,
+    /// Mezcla con la imagen original: 0.0 sin efecto, 1.0 LUT completa
+    pub intensity: f32,
+}
+
+impl LutGrade {
+    pub fn new(lut: Lut3D) -> Self {
+        Self { lut, intensity: 1.0 }
+    }
+
+    pub fn load(path: &str) -> Result<Self, String> {
+        Ok(Self::new(Lut3D::load(path)?))
+    }
+
+    pub fn with_intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+}
+
+impl PostEffect for LutGrade {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], _width: usize, _height: usize) {
+        let intensity = self.intensity.clamp(0.0, 1.0);
+        for (out, &pixel) in dst.iter_mut().zip(src) {
+            let rgb = unpack_rgb(pixel);
+            let graded = self.lut.sample(rgb.map(|value| value / 255.0));
+            let mixed = std::array::from_fn(|i| rgb[i] + (graded[i] * 255.0 - rgb[i]) * intensity);
+            *out = pack_rgb(pixel, mixed);
+        }
+    }
+    fn name(&self) -> &str {
+        "LutGrade"
+    }
+}
+
+/// Curva tonal suave (Hermite monótona) que pasa por puntos de control en 0.0 .. 1.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct Curve {
+    points: Vec<(f32, f32)>,
+    table: Vec<u8>,
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Curve {
+    pub fn identity() -> Self {
+        Self::new(&[(0.0, 0.0), (1.0, 1.0)])
+    }
+
+    /// Los puntos se ordenan por `x`; se fijan los extremos si faltan.
+    /// Sin puntos devuelve la identidad
+    pub fn new(points: &[(f32, f32)]) -> Self {
+        if points.is_empty() {
+            return Self::identity();
+        }
+        let mut points: Vec<(f32, f32)> = points
+            .iter()
+            .map(|&(x, y)| (x.clamp(0.0, 1.0), y.clamp(0.0, 1.0)))
+            .collect();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        points.dedup_by(|a, b| a.0 == b.0);
+        if points.first().is_none_or(|p| p.0 > 0.0) {
+            points.insert(0, (0.0, points.first().map_or(0.0, |p| p.1)));
+        }
+        if points.last().is_none_or(|p| p.0 < 1.0) {
+            points.push((1.0, points.last().map_or(1.0, |p| p.1)));
+        }
+
+        let mut curve = Self {
+            points,
+            table: Vec::new(),
+        };
+        curve.table = (0..256)
+            .map(|i| (curve.eval(i as f32 / 255.0) * 255.0).round() as u8)
+            .collect();
+        curve
+    }
+
+    /// Curva en "S": `amount` > 0 sube el contraste, < 0 lo baja
+    pub fn s_curve(amount: f32) -> Self {
+        let amount = amount.clamp(-1.0, 1.0) * 0.15;
+        Self::new(&[(0.0, 0.0), (0.25, 0.25 - amount), (0.75, 0.75 + amount), (1.0, 1.0)])
+    }
+
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.points
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.table.iter().enumerate().all(|(i, &value)| value as usize == i)
+    }
+
+    /// Valor de la curva en `x` (0.0 .. 1.0)
+    pub fn eval(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        let points = &self.points;
+        let segment = points
+            .windows(2)
+            .position(|pair| x <= pair[1].0)
+            .unwrap_or(points.len() - 2);
+
+        let (x0, y0) = points[segment];
+        let (x1, y1) = points[segment + 1];
+        let h = x1 - x0;
+        if h <= f32::EPSILON {
+            return y0;
+        }
+        let m0 = self.tangent(segment);
+        let m1 = self.tangent(segment + 1);
+
+        let t = (x - x0) / h;
+        let (t2, t3) = (t * t, t * t * t);
+        let y = (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+            + (t3 - 2.0 * t2 + t) * h * m0
+            + (-2.0 * t3 + 3.0 * t2) * y1
+            + (t3 - t2) * h * m1;
+        y.clamp(0.0, 1.0)
+    }
+
+    /// Aplica la curva a un canal de 8 bits
+    pub fn map(&self, value: u8) -> u8 {
+        self.table[value as usize]
+    }
+
+    // Tangente de Fritsch–Carlson: evita que la curva se pase entre puntos
+    fn tangent(&self, index: usize) -> f32 {
+        let points = &self.points;
+        let slope = |i: usize| {
+            let (x0, y0) = points[i];
+            let (x1, y1) = points[i + 1];
+            (y1 - y0) / (x1 - x0)
+        };
+
+        if index == 0 {
+            return slope(0);
+        }
+        if index == points.len() - 1 {
+            return slope(index - 1);
+        }
+        let (before, after) = (slope(index - 1), slope(index));
+        if before * after <= 0.0 {
+            0.0
+        } else {
+            // Media armónica ponderada
+            let h0 = points[index].0 - points[index - 1].0;
+            let h1 = points[index + 1].0 - points[index].0;
+            let w0 = 2.0 * h1 + h0;
+            let w1 = h1 + 2.0 * h0;
+            (w0 + w1) / (w0 / before + w1 / after)
+        }
+    }
+}
+
+/// Ajustes de color sobre el frame final: exposición, contraste, saturación,
+/// giro de tono y curvas por canal, en ese orden.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorGrade {
+    /// En pasos (stops): +1.0 duplica la luz, -1.0 la reduce a la mitad
+    pub exposure: f32,
+    /// 1.0 neutro, alrededor del gris medio
+    pub contrast: f32,
+    /// 1.0 neutro, 0.0 escala de grises
+    pub saturation: f32,
+    /// Giro del tono en grados
+    pub hue_shift: f32,
+    /// Curva aplicada a los tres canales
+    pub master: Curve,
+    pub red: Curve,
+    pub green: Curve,
+    pub blue: Curve,
+}
+
+impl Default for ColorGrade {
+    fn default() -> Self {
+        Self {
+            exposure: 0.0,
+            contrast: 1.0,
+            saturation: 1.0,
+            hue_shift: 0.0,
+            master: Curve::identity(),
+            red: Curve::identity(),
+            green: Curve::identity(),
+            blue: Curve::identity(),
+        }
+    }
+}
+
+impl ColorGrade {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_exposure(mut self, stops: f32) -> Self {
+        self.exposure = stops;
+        self
+    }
+
+    pub fn with_contrast(mut self, contrast: f32) -> Self {
+        self.contrast = contrast;
+        self
+    }
+
+    pub fn with_saturation(mut self, saturation: f32) -> Self {
+        self.saturation = saturation;
+        self
+    }
+
+    pub fn with_hue_shift(mut self, degree: f32) -> Self {
+        self.hue_shift = degree;
+        self
+    }
+
+    pub fn with_curves(mut self, master: Curve, red: Curve, green: Curve, blue: Curve) -> Self {
+        self.master = master;
+        self.red = red;
+        self.green = green;
+        self.blue = blue;
+        self
+    }
+
+    pub fn apply_color(&self, color: Color) -> Color {
+        let mut rgb = [color.r, color.g, color.b].map(|value| value as f32 / 255.0);
+
+        if self.exposure != 0.0 {
+            // La exposición multiplica la luz, así que se aplica en sRGB lineal
+            let gain = self.exposure.exp2();
+            rgb = rgb.map(|value| linear_to_srgb(srgb_to_linear(value) * gain));
+        }
+
+        if self.contrast != 1.0 {
+            rgb = rgb.map(|value| (value - 0.5) * self.contrast + 0.5);
+        }
+
+        if self.saturation != 1.0 {
+            let luma = 0.2126 * rgb[0] + 0.7152 * rgb[1] + 0.0722 * rgb[2];
+            rgb = rgb.map(|value| luma + (value - luma) * self.saturation);
+        }
+
+        let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let mut color = Color::rgba(channel(rgb[0]), channel(rgb[1]), channel(rgb[2]), color.a);
+
+        if self.hue_shift != 0.0 {
+            let (h, s, l) = color.to_hsl();
+            color = Color::hsla(h + self.hue_shift, s, l, color.a);
+        }
+
+        color.r = self.red.map(self.master.map(color.r));
+        color.g = self.green.map(self.master.map(color.g));
+        color.b = self.blue.map(self.master.map(color.b));
+        color
+    }
+}
+
+impl PostEffect for ColorGrade {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], _width: usize, _height: usize) {
+        for (out, &pixel) in dst.iter_mut().zip(src) {
+            let [a, r, g, b] = pixel.to_be_bytes();
+            *out = self.apply_color(Color::rgba(r, g, b, a)).to_hex();
+        }
+    }
+    fn name(&self) -> &str {
+        "ColorGrade"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // LUT 2x2x2 que invierte los colores, con rojo variando más rápido
+    const INVERT: &str = "# comentario
+TITLE \"Invert\"
+LUT_3D_SIZE 2
+DOMAIN_MIN 0 0 0
+DOMAIN_MAX 1 1 1
+
+1 1 1
+0 1 1
+1 0 1
+0 0 1
+1 1 0
+0 1 0
+1 0 0
+0 0 0
+";
+
+    #[test]
+    fn parse_cube() {
+        let lut = Lut3D::parse(INVERT).unwrap();
+        assert_eq!(lut.title, "Invert");
+        assert_eq!(lut.size(), 2);
+        assert_eq!(lut.sample([1.0, 0.0, 0.0]), [0.0, 1.0, 1.0]);
+        assert_eq!(lut.sample([0.25, 0.5, 1.0]), [0.75, 0.5, 0.0]);
+        let color = lut.apply_color(Color::rgba(255, 0, 51, 7));
+        assert_eq!([color.r, color.g, color.b, color.a], [0, 255, 204, 7]);
+    }
+
+    #[test]
+    fn parse_cube_domain() {
+        let text = INVERT.replace("DOMAIN_MAX 1 1 1", "LUT_3D_INPUT_RANGE 0 2");
+        let lut = Lut3D::parse(&text).unwrap();
+        assert_eq!(lut.sample([1.0, 2.0, 0.0]), [0.5, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn identity_round_trip() {
+        let lut = Lut3D::identity(17);
+        for value in [0, 1, 64, 127, 200, 255] {
+            let color = lut.apply_color(Color::rgb(value, 255 - value, value / 2));
+            assert_eq!([color.r, color.g, color.b], [value, 255 - value, value / 2]);
+        }
+    }
+
+    #[test]
+    fn parse_cube_errors() {
+        assert!(Lut3D::parse("0 0 0").unwrap_err().contains("LUT_3D_SIZE"));
+        assert!(
+            Lut3D::parse("LUT_3D_SIZE 1")
+                .unwrap_err()
+                .contains("line 1")
+        );
+        assert!(Lut3D::parse("LUT_1D_SIZE 16").is_err());
+        let (short, _) = INVERT.trim_end().rsplit_once('\n').unwrap();
+        assert!(Lut3D::parse(short).unwrap_err().contains("found 7"));
+        let bad = INVERT.replace("0 1 0", "0 x 0");
+        assert!(Lut3D::parse(&bad).unwrap_err().contains("line 12"));
+    }
+
+    #[test]
+    fn curves() {
+        assert!(Curve::identity().is_identity());
+        assert!(Curve::new(&[]).is_identity());
+        // Un solo punto da una curva plana
+        let flat = Curve::new(&[(0.3, 0.5)]);
+        assert_eq!((flat.map(0), flat.map(255)), (128, 128));
+        // Monótona y sin pasarse entre puntos
+        let s = Curve::s_curve(1.0);
+        assert_eq!((s.map(0), s.map(255)), (0, 255));
+        assert!(s.map(64) < 64 && s.map(192) > 192);
+        let table: Vec<u8> = (0..=255).map(|value| s.map(value)).collect();
+        assert!(table.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::{Index, IndexMut};
+
+use super::{gradient::Gradient, texture::Texture};
+
+/// Campo escalar 2D guardado fila por fila: alturas, densidades, ruido...
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScalarGrid {
+    width: usize,
+    height: usize,
+    values: Vec<f32>,
+}
+
+impl ScalarGrid {
+    /// Cuadrícula llena de ceros
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            values: vec![0.0; width * height],
+        }
+    }
+
+    /// Cada celda con `f(x, y)`
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(usize, usize) -> f32) -> Self {
+        let mut grid = Self::new(width, height);
+        grid.fill_with(f);
+        grid
+    }
+
+    pub fn from_values(width: usize, height: usize, values: Vec<f32>) -> Result<Self, String> {
+        if values.len() != width * height {
+            return Err(format!(
+                "Grid of {}x{} needs {} values, got {}",
+                width,
+                height,
+                width * height,
+                values.len()
+            ));
+        }
+        Ok(Self {
+            width,
+            height,
+            values,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn values(&self) -> &[f32] {
+        &self.values
+    }
+
+    pub fn values_mut(&mut self) -> &mut [f32] {
+        &mut self.values
+    }
+
+    /// Valor en (x, y), `None` fuera de los límites
+    pub fn get(&self, x: usize, y: usize) -> Option<f32> {
+        (x < self.width && y < self.height).then(|| self.values[y * self.width + x])
+    }
+
+    /// Fuera de los límites no hace nada
+    pub fn set(&mut self, x: usize, y: usize, value: f32) {
+        if x < self.width && y < self.height {
+            self.values[y * self.width + x] = value;
+        }
+    }
+
+    /// Interpolación bilineal entre celdas; fuera de la cuadrícula se usa el borde
+    pub fn sample(&self, x: f32, y: f32) -> f32 {
+        if self.values.is_empty() {
+            return 0.0;
+        }
+        let x = x.clamp(0.0, (self.width - 1) as f32);
+        let y = y.clamp(0.0, (self.height - 1) as f32);
+        let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+        let (x1, y1) = ((x0 + 1).min(self.width - 1), (y0 + 1).min(self.height - 1));
+        let (tx, ty) = (x - x0 as f32, y - y0 as f32);
+
+        let top = self[(x0, y0)] + (self[(x1, y0)] - self[(x0, y0)]) * tx;
+        let bottom = self[(x0, y1)] + (self[(x1, y1)] - self[(x0, y1)]) * tx;
+        top + (bottom - top) * ty
+    }
+
+    pub fn fill(&mut self, value: f32) {
+        self.values.fill(value);
+    }
+
+    pub fn fill_with(&mut self, mut f: impl FnMut(usize, usize) -> f32) {
+        let width = self.width.max(1);
+        for (index, value) in self.values.iter_mut().enumerate() {
+            *value = f(index % width, index / width);
+        }
+    }
+
+    /// Aplica `f` a cada valor
+    pub fn map(&mut self, f: impl Fn(f32) -> f32) {
+        for value in self.values.iter_mut() {
+            *value = f(*value);
+        }
+    }
+
+    /// Valores mínimo y máximo; (0.0, 0.0) si está vacía
+    pub fn min_max(&self) -> (f32, f32) {
+        if self.values.is_empty() {
+            return (0.0, 0.0);
+        }
+        self.values
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            })
+    }
+
+    /// Reescala los valores a 0.0 .. 1.0
+    pub fn normalize(&mut self) {
+        let (min, max) = self.min_max();
+        let range = max - min;
+        if range <= f32::EPSILON {
+            self.fill(0.0);
+            return;
+        }
+        self.map(|value| (value - min) / range);
+    }
+
+    /// Textura coloreada con el degradado, de `min` (t = 0) a `max` (t = 1)
+    pub fn to_texture_in(&self, gradient: &Gradient, (min, max): (f32, f32)) -> Texture {
+        let lut: Vec<u32> = gradient
+            .lookup_table(256)
+            .iter()
+            .map(|c| c.to_hex())
+            .collect();
+        let range = (max - min).max(f32::EPSILON);
+        let data = self
+            .values
+            .iter()
+            .map(|value| {
+                let t = ((value - min) / range).clamp(0.0, 1.0);
+                lut[(t * 255.0).round() as usize]
+            })
+            .collect();
+        Texture::new(self.width as u32, self.height as u32, data)
+    }
+
+    /// Textura coloreada con el degradado usando todo el rango de la cuadrícula
+    pub fn to_texture(&self, gradient: &Gradient) -> Texture {
+        self.to_texture_in(gradient, self.min_max())
+    }
+}
+
+impl Index<(usize, usize)> for ScalarGrid {
+    type Output = f32;
+
+    fn index(&self, (x, y): (usize, usize)) -> &f32 {
+        assert!(
+            x < self.width && y < self.height,
+            "grid index ({x}, {y}) out of bounds"
+        );
+        &self.values[y * self.width + x]
+    }
+}
+
+impl IndexMut<(usize, usize)> for ScalarGrid {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut f32 {
+        assert!(
+            x < self.width && y < self.height,
+            "grid index ({x}, {y}) out of bounds"
+        );
+        &mut self.values[y * self.width + x]
+    }
+}
@@ -0,0 +1,579 @@
+use std::collections::HashMap;
+
+use glam::{Affine2, Vec2};
+
+use super::{
+    canvas::Canvas,
+    colores::Color,
+    grid::ScalarGrid,
+    shape::{Shape, Vertex},
+    texture::Material,
+    transform::Transform,
+};
+
+/// Curva de nivel: puntos conectados donde el campo vale `level`.
+/// Las coordenadas son las de la cuadrícula (una unidad por celda).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Isoline {
+    pub level: f32,
+    pub points: Vec<Vec2>,
+    /// El último punto se une con el primero
+    pub closed: bool,
+}
+
+impl Isoline {
+    /// Longitud total, incluido el tramo de cierre
+    pub fn length(&self) -> f32 {
+        self.segments().map(|(a, b)| a.distance(b)).sum()
+    }
+
+    /// Pares de puntos consecutivos
+    pub fn segments(&self) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+        let closing = match (self.closed, self.points.first(), self.points.last()) {
+            (true, Some(&first), Some(&last)) if self.points.len() > 2 => Some((last, first)),
+            _ => None,
+        };
+        self.points
+            .windows(2)
+            .map(|pair| (pair[0], pair[1]))
+            .chain(closing)
+    }
+
+    /// Aplica una matriz a todos los puntos (p. ej. escalar de celdas a píxeles)
+    pub fn transformed(mut self, matrix: Affine2) -> Self {
+        for point in self.points.iter_mut() {
+            *point = matrix.transform_point2(*point);
+        }
+        self
+    }
+
+    /// Dibuja la curva con líneas, después de la vista de la cámara del canvas
+    pub fn draw<C: Canvas + ?Sized>(&self, canvas: &mut C, color: u32) {
+        let view = canvas
+            .camera()
+            .filter(|camera| !camera.is_identity())
+            .map(|camera| camera.view_matrix());
+        for (a, b) in self.segments() {
+            let (a, b) = match view {
+                Some(view) => (view.transform_point2(a), view.transform_point2(b)),
+                None => (a, b),
+            };
+            let (a, b) = (a.round(), b.round());
+            // draw_line trabaja con usize: los tramos con coordenadas negativas se omiten
+            if a.min_element() < 0.0 || b.min_element() < 0.0 {
+                continue;
+            }
+            canvas.draw_line(
+                a.x as usize,
+                a.y as usize,
+                b.x as usize,
+                b.y as usize,
+                color,
+            );
+        }
+    }
+}
+
+/// Región del campo con valores en `lower..upper`, triangulada.
+/// Las coordenadas son las de la cuadrícula (una unidad por celda).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IsoBand {
+    pub lower: f32,
+    pub upper: f32,
+    pub vertices: Vec<Vec2>,
+    pub indices: Vec<[usize; 3]>,
+}
+
+impl IsoBand {
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    pub fn area(&self) -> f32 {
+        self.indices
+            .iter()
+            .map(|&[a, b, c]| {
+                let (a, b, c) = (self.vertices[a], self.vertices[b], self.vertices[c]);
+                (b - a).perp_dot(c - a).abs() * 0.5
+            })
+            .sum()
+    }
+
+    /// Aplica una matriz a todos los vértices
+    pub fn transformed(mut self, matrix: Affine2) -> Self {
+        for vertex in self.vertices.iter_mut() {
+            *vertex = matrix.transform_point2(*vertex);
+        }
+        self
+    }
+
+    /// Shape de un solo color listo para `draw_shape`
+    pub fn to_shape(&self, color: Color) -> Shape {
+        Shape {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|point| Vertex::new(point.x, point.y, color, Vec2::ZERO))
+                .collect(),
+            indices: self.indices.clone(),
+            transform: Transform::identity(),
+            material: Material::default(),
+            layer: 0,
+            origen: false,
+        }
+        .with_color(color)
+    }
+}
+
+/// Curvas de nivel del campo por marching squares, una o más por cada nivel.
+///
+/// Los cruces se interpolan linealmente sobre las aristas y las celdas de silla se
+/// resuelven con el valor del centro de la interpolación bilineal (asymptotic decider).
+pub fn isolines(grid: &ScalarGrid, levels: &[f32]) -> Vec<Isoline> {
+    levels
+        .iter()
+        .flat_map(|&level| isolines_at(grid, level))
+        .collect()
+}
+
+/// Como `isolines` pero muestreando `f` cada `step` unidades; los puntos quedan en
+/// el espacio de `f`
+pub fn isolines_fn(
+    width: usize,
+    height: usize,
+    step: f32,
+    f: impl Fn(f32, f32) -> f32,
+    levels: &[f32],
+) -> Vec<Isoline> {
+    let grid = ScalarGrid::from_fn(width, height, |x, y| f(x as f32 * step, y as f32 * step));
+    let scale = Affine2::from_scale(Vec2::splat(step));
+    isolines(&grid, levels)
+        .into_iter()
+        .map(|line| line.transformed(scale))
+        .collect()
+}
+
+/// Bandas entre cada par de umbrales consecutivos (ordenados de menor a mayor).
+/// Sus bordes coinciden con las curvas de `isolines` para los mismos niveles.
+pub fn isobands(grid: &ScalarGrid, thresholds: &[f32]) -> Vec<IsoBand> {
+    thresholds
+        .windows(2)
+        .map(|pair| isoband(grid, pair[0], pair[1]))
+        .collect()
+}
+
+/// Como `isobands` pero muestreando `f` cada `step` unidades
+pub fn isobands_fn(
+    width: usize,
+    height: usize,
+    step: f32,
+    f: impl Fn(f32, f32) -> f32,
+    thresholds: &[f32],
+) -> Vec<IsoBand> {
+    let grid = ScalarGrid::from_fn(width, height, |x, y| f(x as f32 * step, y as f32 * step));
+    let scale = Affine2::from_scale(Vec2::splat(step));
+    isobands(&grid, thresholds)
+        .into_iter()
+        .map(|band| band.transformed(scale))
+        .collect()
+}
+
+// Esquinas de una celda recorriendo su borde: superior izquierda, superior derecha,
+// inferior derecha e inferior izquierda
+fn cell_corners(grid: &ScalarGrid, x: usize, y: usize) -> [(Vec2, f32); 4] {
+    [(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)]
+        .map(|(cx, cy)| (Vec2::new(cx as f32, cy as f32), grid[(cx, cy)]))
+}
+
+// Valor de la interpolación bilineal en su punto de silla
+fn saddle_value(corners: &[(Vec2, f32); 4]) -> f32 {
+    let [a, b, c, d] = corners.map(|(_, value)| value);
+    let denominator = a + c - b - d;
+    if denominator.abs() <= f32::EPSILON {
+        (a + b + c + d) * 0.25
+    } else {
+        (a * c - b * d) / denominator
+    }
+}
+
+// Punto donde el campo vale `level` entre dos esquinas
+fn crossing((p, a): (Vec2, f32), (q, b): (Vec2, f32), level: f32) -> Vec2 {
+    let range = b - a;
+    let t = if range.abs() <= f32::EPSILON {
+        0.5
+    } else {
+        ((level - a) / range).clamp(0.0, 1.0)
+    };
+    p.lerp(q, t)
+}
+
+// Aristas de la celda en el orden del borde, con el id global de cada una:
+// horizontales en pares, verticales en impares
+fn cell_edges(width: usize, x: usize, y: usize) -> [usize; 4] {
+    let horizontal = |x: usize, y: usize| (y * width + x) * 2;
+    let vertical = |x: usize, y: usize| (y * width + x) * 2 + 1;
+    [
+        horizontal(x, y),
+        vertical(x + 1, y),
+        horizontal(x, y + 1),
+        vertical(x, y),
+    ]
+}
+
+fn isolines_at(grid: &ScalarGrid, level: f32) -> Vec<Isoline> {
+    let (width, height) = grid.size();
+    if width < 2 || height < 2 {
+        return vec![];
+    }
+
+    // Cada segmento une dos aristas; cada arista guarda su punto de cruce
+    let mut points: HashMap<usize, Vec2> = HashMap::new();
+    let mut segments: Vec<[usize; 2]> = vec![];
+
+    for y in 0..height - 1 {
+        for x in 0..width - 1 {
+            let corners = cell_corners(grid, x, y);
+            let above = corners.map(|(_, value)| value >= level);
+            let edges = cell_edges(width, x, y);
+
+            let mut crossed = [0; 4];
+            let mut count = 0;
+            for side in 0..4 {
+                let next = (side + 1) % 4;
+                if above[side] != above[next] {
+                    // Siempre se interpola desde la misma esquina para que las dos
+                    // celdas vecinas obtengan exactamente el mismo punto
+                    let (from, to) = if side < 2 { (side, next) } else { (next, side) };
+                    points
+                        .entry(edges[side])
+                        .or_insert_with(|| crossing(corners[from], corners[to], level));
+                    crossed[count] = edges[side];
+                    count += 1;
+                }
+            }
+
+            match count {
+                2 => segments.push([crossed[0], crossed[1]]),
+                4 => {
+                    // En una silla se cruzan las cuatro aristas: la esquina superior derecha
+                    // queda aislada salvo que la silla conecte las esquinas de su lado
+                    let connected = (saddle_value(&corners) >= level) == above[1];
+                    if connected {
+                        segments.push([crossed[3], crossed[0]]);
+                        segments.push([crossed[1], crossed[2]]);
+                    } else {
+                        segments.push([crossed[0], crossed[1]]);
+                        segments.push([crossed[2], crossed[3]]);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    chain_segments(level, &points, &segments)
+}
+
+// Une los segmentos que comparten arista en polilíneas
+fn chain_segments(
+    level: f32,
+    points: &HashMap<usize, Vec2>,
+    segments: &[[usize; 2]],
+) -> Vec<Isoline> {
+    let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (index, segment) in segments.iter().enumerate() {
+        for &edge in segment {
+            adjacency.entry(edge).or_default().push(index);
+        }
+    }
+
+    let mut used = vec![false; segments.len()];
+    let mut lines = vec![];
+
+    // Primero las curvas abiertas, que empiezan en una arista con un solo segmento
+    let open_starts = segments
+        .iter()
+        .enumerate()
+        .flat_map(|(index, segment)| segment.iter().map(move |&edge| (index, edge)))
+        .filter(|(_, edge)| adjacency[edge].len() == 1);
+    let loop_starts = segments
+        .iter()
+        .enumerate()
+        .map(|(index, segment)| (index, segment[0]));
+
+    for (start_segment, start_edge) in open_starts.chain(loop_starts).collect::<Vec<_>>() {
+        if used[start_segment] {
+            continue;
+        }
+
+        let mut line = Isoline {
+            level,
+            points: vec![points[&start_edge]],
+            closed: false,
+        };
+        let (mut segment, mut edge) = (start_segment, start_edge);
+        loop {
+            used[segment] = true;
+            let [a, b] = segments[segment];
+            edge = if a == edge { b } else { a };
+
+            if edge == start_edge {
+                line.closed = true;
+                break;
+            }
+            let point = points[&edge];
+            if line
+                .points
+                .last()
+                .is_none_or(|last| last.distance_squared(point) > 1e-12)
+            {
+                line.points.push(point);
+            }
+
+            match adjacency[&edge].iter().find(|&&next| !used[next]) {
+                Some(&next) => segment = next,
+                None => break,
+            }
+        }
+
+        if line.points.len() > 1 {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+// Parte de la celda a un lado del nivel, en polígonos convexos; en las sillas se
+// usa el mismo criterio que `isolines_at` para que bandas y curvas coincidan
+fn cell_region(corners: &[(Vec2, f32); 4], level: f32, above: bool, saddle: f32) -> Vec<Vec<Vec2>> {
+    let inside = corners.map(|(_, value)| (value >= level) == above);
+    match inside.iter().filter(|&&inside| inside).count() {
+        0 => return vec![],
+        4 => return vec![corners.map(|(point, _)| point).to_vec()],
+        _ => (),
+    }
+
+    let mut polygon = vec![];
+    let mut crossings = 0;
+    for side in 0..4 {
+        let next = (side + 1) % 4;
+        if inside[side] {
+            polygon.push(corners[side].0);
+        }
+        if inside[side] != inside[next] {
+            let (from, to) = if side < 2 { (side, next) } else { (next, side) };
+            polygon.push(crossing(corners[from], corners[to], level));
+            crossings += 1;
+        }
+    }
+
+    let connected = (saddle >= level) == above;
+    if crossings < 4 || connected {
+        return vec![polygon];
+    }
+
+    // Silla con las esquinas separadas: un triángulo por esquina
+    (0..4)
+        .filter(|&corner| inside[corner])
+        .map(|corner| {
+            let previous = (corner + 3) % 4;
+            let next = (corner + 1) % 4;
+            let before = if previous < 2 {
+                (previous, corner)
+            } else {
+                (corner, previous)
+            };
+            let after = if corner < 2 {
+                (corner, next)
+            } else {
+                (next, corner)
+            };
+            vec![
+                crossing(corners[before.0], corners[before.1], level),
+                corners[corner].0,
+                crossing(corners[after.0], corners[after.1], level),
+            ]
+        })
+        .collect()
+}
+
+// Sutherland-Hodgman de un polígono convexo contra otro convexo
+fn clip_convex(subject: &[Vec2], clip: &[Vec2]) -> Vec<Vec2> {
+    let orientation: f32 = clip
+        .iter()
+        .zip(clip.iter().cycle().skip(1))
+        .map(|(a, b)| a.perp_dot(*b))
+        .sum();
+    if orientation.abs() <= f32::EPSILON {
+        return vec![];
+    }
+    let sign = orientation.signum();
+
+    let mut output = subject.to_vec();
+    for (&a, &b) in clip.iter().zip(clip.iter().cycle().skip(1)) {
+        if output.is_empty() {
+            break;
+        }
+        let side = |p: Vec2| (b - a).perp_dot(p - a) * sign;
+        let input = std::mem::take(&mut output);
+        for (&p, &q) in input.iter().zip(input.iter().cycle().skip(1)) {
+            let (sp, sq) = (side(p), side(q));
+            if sp >= 0.0 {
+                output.push(p);
+            }
+            if (sp >= 0.0) != (sq >= 0.0) {
+                output.push(p.lerp(q, sp / (sp - sq)));
+            }
+        }
+    }
+    output
+}
+
+// Triangula en abanico un polígono convexo
+fn push_polygon(band: &mut IsoBand, polygon: &[Vec2]) {
+    let area: f32 = polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(a, b)| a.perp_dot(*b))
+        .sum();
+    if polygon.len() < 3 || area.abs() <= 1e-9 {
+        return;
+    }
+    let base = band.vertices.len();
+    band.vertices.extend_from_slice(polygon);
+    band.indices
+        .extend((1..polygon.len() - 1).map(|i| [base, base + i, base + i + 1]));
+}
+
+fn isoband(grid: &ScalarGrid, lower: f32, upper: f32) -> IsoBand {
+    let mut band = IsoBand {
+        lower,
+        upper,
+        ..Default::default()
+    };
+    let (width, height) = grid.size();
+    if width < 2 || height < 2 {
+        return band;
+    }
+
+    for y in 0..height - 1 {
+        for x in 0..width - 1 {
+            let corners = cell_corners(grid, x, y);
+            let (min, max) = corners.iter().fold(
+                (f32::INFINITY, f32::NEG_INFINITY),
+                |(min, max), &(_, value)| (min.min(value), max.max(value)),
+            );
+            if max < lower || min >= upper {
+                continue;
+            }
+            if min >= lower && max < upper {
+                push_polygon(&mut band, &corners.map(|(point, _)| point));
+                continue;
+            }
+
+            let saddle = saddle_value(&corners);
+            let above_lower = cell_region(&corners, lower, true, saddle);
+            let below_upper = cell_region(&corners, upper, false, saddle);
+            for a in &above_lower {
+                for b in &below_upper {
+                    push_polygon(&mut band, &clip_convex(a, b));
+                }
+            }
+        }
+    }
+    band
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Cono con el vértice en (10, 10): el campo es la distancia al centro
+    fn cone() -> ScalarGrid {
+        ScalarGrid::from_fn(21, 21, |x, y| {
+            Vec2::new(x as f32, y as f32).distance(Vec2::splat(10.0))
+        })
+    }
+
+    #[test]
+    fn ramp_gives_open_line() {
+        let grid = ScalarGrid::from_fn(5, 4, |x, _| x as f32);
+        let lines = isolines(&grid, &[1.5]);
+        assert_eq!(lines.len(), 1);
+        let line = &lines[0];
+        assert!(!line.closed);
+        assert_eq!(line.points.len(), 4);
+        assert!(line.points.iter().all(|point| (point.x - 1.5).abs() < 1e-6));
+        assert!((line.length() - 3.0).abs() < 1e-5);
+        assert!(isolines(&grid, &[-1.0, 10.0]).is_empty());
+    }
+
+    #[test]
+    fn circle_is_closed() {
+        let lines = isolines(&cone(), &[5.0, 8.5]);
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            assert!(line.closed);
+            assert!(
+                line.points
+                    .iter()
+                    .all(|point| { (point.distance(Vec2::splat(10.0)) - line.level).abs() < 0.1 })
+            );
+            let circumference = std::f32::consts::TAU * line.level;
+            assert!((line.length() - circumference).abs() / circumference < 0.02);
+        }
+    }
+
+    #[test]
+    fn saddle_cells() {
+        let grid = ScalarGrid::from_values(2, 2, vec![1.0, 0.0, 0.0, 1.0]).unwrap();
+        let lines = isolines(&grid, &[0.4]);
+        assert_eq!(lines.len(), 2);
+        assert!(
+            lines
+                .iter()
+                .all(|line| !line.closed && line.points.len() == 2)
+        );
+        // Bandas y curvas usan el mismo criterio: las áreas siguen sumando la celda
+        let bands = isobands(&grid, &[-1.0, 0.4, 2.0]);
+        let total: f32 = bands.iter().map(IsoBand::area).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn band_areas() {
+        let ramp = ScalarGrid::from_fn(5, 4, |x, _| x as f32);
+        let bands = isobands(&ramp, &[1.0, 3.0, 10.0]);
+        assert!((bands[0].area() - 6.0).abs() < 1e-5);
+        assert!((bands[1].area() - 3.0).abs() < 1e-5);
+        assert!(isobands(&ramp, &[5.0, 6.0])[0].is_empty());
+
+        let disc = &isobands(&cone(), &[0.0, 5.0])[0];
+        let expected = std::f32::consts::PI * 25.0;
+        assert!((disc.area() - expected).abs() / expected < 0.02);
+    }
+
+    #[test]
+    fn bands_cover_the_grid() {
+        let grid = ScalarGrid::from_fn(12, 9, |x, y| {
+            (x as f32 * 0.7).sin() * (y as f32 * 0.5).cos()
+        });
+        let bands = isobands(&grid, &[-2.0, -0.5, 0.0, 0.3, 2.0]);
+        let total: f32 = bands.iter().map(IsoBand::area).sum();
+        assert!((total - 88.0).abs() < 1e-3, "{total}");
+    }
+
+    #[test]
+    fn sampled_functions_use_their_space() {
+        let lines = isolines_fn(11, 11, 0.5, |x, y| x + y, &[2.0]);
+        assert_eq!(lines.len(), 1);
+        assert!(
+            lines[0]
+                .points
+                .iter()
+                .all(|point| (point.x + point.y - 2.0).abs() < 1e-5)
+        );
+        let bands = isobands_fn(11, 11, 0.5, |x, _| x, &[1.0, 2.0]);
+        assert!((bands[0].area() - 5.0).abs() < 1e-4);
+    }
+}
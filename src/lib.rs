@@ -5,6 +5,30 @@ pub mod utils;
 pub mod draws;
 pub mod auxiliar;
 pub mod colores;
+pub mod viewport;
+pub mod camera;
+pub mod scene;
+pub mod animation;
+pub mod sprite;
+pub mod assets;
+pub mod atlas;
+pub mod canvas;
+pub mod render_target;
+pub mod postprocess;
+pub mod effects;
+pub mod grading;
+pub mod palette;
+pub mod color_space;
+pub mod color_parse;
+pub mod linear_color;
+pub mod random;
+pub mod gradient;
+pub mod paint;
+pub mod grid;
+pub mod noise;
+pub mod sdf;
+pub mod isolines;
+pub mod spatial;
 
 pub mod prelude {
     pub use super::transform::*;
@@ -14,8 +38,33 @@ pub mod prelude {
     pub use super::draws::*;
     pub use super::auxiliar::*;
     pub use super::colores::*;
+    pub use super::viewport::*;
+    pub use super::camera::*;
+    pub use super::scene::*;
+    pub use super::animation::*;
+    pub use super::sprite::*;
+    pub use super::assets::*;
+    pub use super::atlas::*;
+    pub use super::canvas::*;
+    pub use super::render_target::*;
+    pub use super::postprocess::*;
+    pub use super::effects::*;
+    pub use super::grading::*;
+    pub use super::palette::*;
+    pub use super::color_space::*;
+    pub use super::color_parse::*;
+    pub use super::linear_color::*;
+    pub use super::random::*;
+    pub use super::gradient::*;
+    pub use super::paint::*;
+    pub use super::grid::*;
+    pub use super::noise::*;
+    pub use super::sdf::*;
+    pub use super::isolines::*;
+    pub use super::spatial::*;
 
     pub use rand::*;
     pub use glam::*;
     pub use image::*;
+    pub use minifb::{Key, MouseButton, MouseMode, WindowOptions};
 }
\ No newline at end of file
@@ -0,0 +1,276 @@
+use std::{
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign},
+    sync::OnceLock,
+};
+
+use super::{
+    color_space::{linear_to_srgb, srgb_to_linear},
+    colores::Color,
+};
+
+/// Color en punto flotante con canales en luz lineal (0.0 .. 1.0).
+///
+/// Al convertir desde `Color` el alfa es directo (sin premultiplicar); los operadores
+/// de Porter–Duff trabajan con colores premultiplicados (`premultiply`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LinearColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+/// Operadores de composición de Porter–Duff: `src` sobre `dst`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PorterDuff {
+    #[default]
+    Over,
+    /// `src` solo donde hay `dst`
+    In,
+    /// `src` solo donde no hay `dst`
+    Out,
+    /// `src` dentro de `dst`, conservando `dst`
+    Atop,
+    /// Cada uno donde el otro no está
+    Xor,
+}
+
+// sRGB (u8) a lineal precalculado: la conversión es exacta y de ida y vuelta
+fn srgb_table() -> &'static [f32; 256] {
+    static TABLE: OnceLock<[f32; 256]> = OnceLock::new();
+    TABLE.get_or_init(|| std::array::from_fn(|i| srgb_to_linear(i as f32 / 255.0)))
+}
+
+fn encode(value: f32) -> u8 {
+    (linear_to_srgb(value.clamp(0.0, 1.0)) * 255.0).round() as u8
+}
+
+impl LinearColor {
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    pub const WHITE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    pub fn from_color(color: Color) -> Self {
+        let table = srgb_table();
+        Self {
+            r: table[color.r as usize],
+            g: table[color.g as usize],
+            b: table[color.b as usize],
+            a: color.a as f32 / 255.0,
+        }
+    }
+
+    /// A sRGB de 8 bits, recortando al rango válido
+    pub fn to_color(self) -> Color {
+        Color::rgba(
+            encode(self.r),
+            encode(self.g),
+            encode(self.b),
+            (self.a.clamp(0.0, 1.0) * 255.0).round() as u8,
+        )
+    }
+
+    /// Desde formato AARRGGBB
+    pub fn from_hex(hex: u32) -> Self {
+        Self::from_color(Color::from_hex(hex))
+    }
+
+    /// A formato AARRGGBB
+    pub fn to_hex(self) -> u32 {
+        self.to_color().to_hex()
+    }
+
+    /// Multiplica los canales por el alfa
+    pub fn premultiply(self) -> Self {
+        Self::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    /// Inverso de `premultiply`; un color totalmente transparente queda en negro
+    pub fn unpremultiply(self) -> Self {
+        if self.a <= f32::EPSILON {
+            return Self::TRANSPARENT;
+        }
+        Self::new(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+    }
+
+    /// Mismo color con el alfa multiplicado (p. ej. por la cobertura del antialiasing)
+    pub fn multiply_alpha(self, alpha: f32) -> Self {
+        Self {
+            a: self.a * alpha,
+            ..self
+        }
+    }
+
+    pub fn clamp(self) -> Self {
+        Self::new(
+            self.r.clamp(0.0, 1.0),
+            self.g.clamp(0.0, 1.0),
+            self.b.clamp(0.0, 1.0),
+            self.a.clamp(0.0, 1.0),
+        )
+    }
+
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Luminancia relativa (Rec. 709)
+    pub fn luminance(self) -> f32 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+
+    /// Compone `self` sobre `dst`; ambos premultiplicados
+    pub fn composite(self, dst: Self, op: PorterDuff) -> Self {
+        let (src_factor, dst_factor) = match op {
+            PorterDuff::Over => (1.0, 1.0 - self.a),
+            PorterDuff::In => (dst.a, 0.0),
+            PorterDuff::Out => (1.0 - dst.a, 0.0),
+            PorterDuff::Atop => (dst.a, 1.0 - self.a),
+            PorterDuff::Xor => (1.0 - dst.a, 1.0 - self.a),
+        };
+        self * src_factor + dst * dst_factor
+    }
+
+    /// `PorterDuff::Over`; ambos premultiplicados
+    pub fn over(self, dst: Self) -> Self {
+        self.composite(dst, PorterDuff::Over)
+    }
+}
+
+/// Mezcla gamma-correcta de `src` sobre `dst` (AARRGGBB, alfa directo)
+pub fn blend_over(dst: u32, src: u32) -> u32 {
+    match src >> 24 {
+        0 => dst,
+        255 => src,
+        _ => {
+            let src = LinearColor::from_hex(src).premultiply();
+            let dst = LinearColor::from_hex(dst).premultiply();
+            src.over(dst).unpremultiply().to_hex()
+        }
+    }
+}
+
+/// Como `blend_over` pero sobre un destino sin alfa real, como el framebuffer de la
+/// ventana: el destino se toma opaco y el resultado también lo es
+pub fn blend_over_opaque(dst: u32, src: u32) -> u32 {
+    match src >> 24 {
+        0 => dst,
+        _ => blend_over(dst | 0xFF00_0000, src),
+    }
+}
+
+impl From<Color> for LinearColor {
+    fn from(color: Color) -> Self {
+        Self::from_color(color)
+    }
+}
+
+impl From<LinearColor> for Color {
+    fn from(color: LinearColor) -> Self {
+        color.to_color()
+    }
+}
+
+impl Add for LinearColor {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.r + rhs.r,
+            self.g + rhs.g,
+            self.b + rhs.b,
+            self.a + rhs.a,
+        )
+    }
+}
+
+impl Sub for LinearColor {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.r - rhs.r,
+            self.g - rhs.g,
+            self.b - rhs.b,
+            self.a - rhs.a,
+        )
+    }
+}
+
+/// Producto canal a canal (modulación)
+impl Mul for LinearColor {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.r * rhs.r,
+            self.g * rhs.g,
+            self.b * rhs.b,
+            self.a * rhs.a,
+        )
+    }
+}
+
+impl Mul<f32> for LinearColor {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self {
+        Self::new(self.r * rhs, self.g * rhs, self.b * rhs, self.a * rhs)
+    }
+}
+
+impl Div<f32> for LinearColor {
+    type Output = Self;
+    fn div(self, rhs: f32) -> Self {
+        self * (1.0 / rhs)
+    }
+}
+
+impl AddAssign for LinearColor {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for LinearColor {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign<f32> for LinearColor {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blend_over_straight_alpha() {
+        assert_eq!(blend_over(0xFF40_4040, 0x0012_3456), 0xFF40_4040);
+        assert_eq!(blend_over(0xFF40_4040, 0xFF12_3456), 0xFF12_3456);
+        // Sobre un destino transparente queda el color de origen
+        assert_eq!(blend_over(0x0040_4040, 0x80FF_FFFF), 0x80FF_FFFF);
+    }
+
+    #[test]
+    fn blend_over_opaque_mixes_with_window_pixels() {
+        // El framebuffer de la ventana guarda 0x00RRGGBB
+        let mixed = blend_over_opaque(0x0040_4040, 0x80FF_FFFF);
+        assert_eq!(mixed >> 24, 0xFF);
+        let channel = mixed & 0xFF;
+        assert!(channel > 0x40 && channel < 0xFF, "{mixed:#010x}");
+        assert_eq!(
+            mixed & 0xFF_FFFF,
+            blend_over(0xFF40_4040, 0x80FF_FFFF) & 0xFF_FFFF
+        );
+        assert_eq!(blend_over_opaque(0x0040_4040, 0x0012_3456), 0x0040_4040);
+    }
+}
@@ -0,0 +1,705 @@
+use std::array::from_fn;
+
+use glam::{Vec2, Vec3, Vec4};
+
+use super::{gradient::Gradient, grid::ScalarGrid, texture::Texture};
+
+/// Ruido coherente en 1, 2, 3 y 4 dimensiones.
+///
+/// `Perlin`, `Simplex` y `ValueNoise` devuelven valores en -1.0 .. 1.0; `Worley`
+/// devuelve distancias (>= 0.0). Los combinadores (`Fractal`, `DomainWarp`) también
+/// son `Noise`, así que se pueden anidar.
+pub trait Noise {
+    fn noise1(&self, x: f32) -> f32 {
+        self.noise2(Vec2::new(x, 0.0))
+    }
+    fn noise2(&self, p: Vec2) -> f32;
+    fn noise3(&self, p: Vec3) -> f32;
+    fn noise4(&self, p: Vec4) -> f32;
+
+    /// Rellena la cuadrícula con `noise2` en `(x, y) * frequency`
+    fn fill_grid(&self, grid: &mut ScalarGrid, frequency: f32) {
+        grid.fill_with(|x, y| self.noise2(Vec2::new(x as f32, y as f32) * frequency));
+    }
+
+    fn to_grid(&self, width: usize, height: usize, frequency: f32) -> ScalarGrid {
+        let mut grid = ScalarGrid::new(width, height);
+        self.fill_grid(&mut grid, frequency);
+        grid
+    }
+
+    /// Rellena la textura coloreando con el degradado todo el rango de valores obtenido
+    fn fill_texture(&self, texture: &mut Texture, frequency: f32, gradient: &Gradient) {
+        let (width, height) = texture.size;
+        let grid = self.to_grid(width as usize, height as usize, frequency);
+        texture.data = grid.to_texture(gradient).data;
+    }
+
+    fn to_texture(&self, width: u32, height: u32, frequency: f32, gradient: &Gradient) -> Texture {
+        self.to_grid(width as usize, height as usize, frequency)
+            .to_texture(gradient)
+    }
+}
+
+impl<N: Noise + ?Sized> Noise for &N {
+    fn noise1(&self, x: f32) -> f32 {
+        (**self).noise1(x)
+    }
+    fn noise2(&self, p: Vec2) -> f32 {
+        (**self).noise2(p)
+    }
+    fn noise3(&self, p: Vec3) -> f32 {
+        (**self).noise3(p)
+    }
+    fn noise4(&self, p: Vec4) -> f32 {
+        (**self).noise4(p)
+    }
+}
+
+impl<N: Noise + ?Sized> Noise for Box<N> {
+    fn noise1(&self, x: f32) -> f32 {
+        (**self).noise1(x)
+    }
+    fn noise2(&self, p: Vec2) -> f32 {
+        (**self).noise2(p)
+    }
+    fn noise3(&self, p: Vec3) -> f32 {
+        (**self).noise3(p)
+    }
+    fn noise4(&self, p: Vec4) -> f32 {
+        (**self).noise4(p)
+    }
+}
+
+// Hash de celdas sin tabla de permutación: admite coordenadas enormes y cualquier semilla
+const PRIMES: [u64; 4] = [
+    0x5205_402B_9270_C86F,
+    0x598C_D327_0038_17B5,
+    0x5BCC_226E_9FA0_BACB,
+    0x56CC_5227_E58F_554B,
+];
+const HASH_MULTIPLIER: u64 = 0x53A3_F72D_EEC5_46F5;
+const GOLDEN: u64 = 0x9E37_79B9_7F4A_7C15;
+
+fn hash<const N: usize>(seed: u64, cell: [i64; N]) -> u64 {
+    let mut h = seed.wrapping_mul(GOLDEN);
+    for (coord, prime) in cell.iter().zip(PRIMES) {
+        h ^= (*coord as u64).wrapping_mul(prime);
+    }
+    h = h.wrapping_mul(HASH_MULTIPLIER);
+    h ^ (h >> 32)
+}
+
+/// 0.0 .. 1.0 a partir de los bits altos
+fn unit(h: u64) -> f32 {
+    (h >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Otro valor independiente del mismo hash (un eje del punto de Worley, etc.)
+fn rehash(h: u64, salt: usize) -> u64 {
+    let h = (h ^ (salt as u64 + 1).wrapping_mul(GOLDEN)).wrapping_mul(HASH_MULTIPLIER);
+    h ^ (h >> 29)
+}
+
+fn quintic(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn dot<const N: usize>(a: [f32; N], b: [f32; N]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+const GRAD1: [f32; 8] = [1.0, -1.0, 0.75, -0.75, 0.5, -0.5, 0.25, -0.25];
+
+const DIAGONAL: f32 = std::f32::consts::FRAC_1_SQRT_2;
+const GRAD2: [[f32; 2]; 8] = [
+    [1.0, 0.0],
+    [-1.0, 0.0],
+    [0.0, 1.0],
+    [0.0, -1.0],
+    [DIAGONAL, DIAGONAL],
+    [-DIAGONAL, DIAGONAL],
+    [DIAGONAL, -DIAGONAL],
+    [-DIAGONAL, -DIAGONAL],
+];
+
+// Direcciones cada 30°, para que el simplex 2D no muestre los ejes
+const GRAD2_SIMPLEX: [[f32; 2]; 12] = [
+    [1.0, 0.0],
+    [0.866_025_4, 0.5],
+    [0.5, 0.866_025_4],
+    [0.0, 1.0],
+    [-0.5, 0.866_025_4],
+    [-0.866_025_4, 0.5],
+    [-1.0, 0.0],
+    [-0.866_025_4, -0.5],
+    [-0.5, -0.866_025_4],
+    [0.0, -1.0],
+    [0.5, -0.866_025_4],
+    [0.866_025_4, -0.5],
+];
+
+// Aristas del cubo de Ken Perlin (las cuatro últimas repetidas para tener 16)
+const GRAD3: [[f32; 3]; 16] = [
+    [1.0, 1.0, 0.0],
+    [-1.0, 1.0, 0.0],
+    [1.0, -1.0, 0.0],
+    [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0],
+    [-1.0, 0.0, 1.0],
+    [1.0, 0.0, -1.0],
+    [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0],
+    [0.0, -1.0, 1.0],
+    [0.0, 1.0, -1.0],
+    [0.0, -1.0, -1.0],
+    [1.0, 1.0, 0.0],
+    [-1.0, 1.0, 0.0],
+    [0.0, -1.0, 1.0],
+    [0.0, -1.0, -1.0],
+];
+
+fn grad1(h: u64) -> [f32; 1] {
+    [GRAD1[(h >> 8) as usize % GRAD1.len()]]
+}
+
+fn grad2(h: u64) -> [f32; 2] {
+    GRAD2[(h >> 8) as usize % GRAD2.len()]
+}
+
+fn grad2_simplex(h: u64) -> [f32; 2] {
+    GRAD2_SIMPLEX[(h >> 8) as usize % GRAD2_SIMPLEX.len()]
+}
+
+fn grad3(h: u64) -> [f32; 3] {
+    GRAD3[(h >> 8) as usize % GRAD3.len()]
+}
+
+/// Las 32 permutaciones de (0, ±1, ±1, ±1)
+fn grad4(h: u64) -> [f32; 4] {
+    let index = (h >> 8) as usize % 32;
+    let zero = index / 8;
+    let mut signs = index % 8;
+    from_fn(|axis| {
+        if axis == zero {
+            return 0.0;
+        }
+        let value = if signs & 1 == 0 { 1.0 } else { -1.0 };
+        signs >>= 1;
+        value
+    })
+}
+
+/// Interpolación multilineal de las 2^N esquinas de la celda con `corner(celda, offset)`
+fn lattice<const N: usize>(p: [f32; N], corner: impl Fn([i64; N], [f32; N]) -> f32) -> f32 {
+    let base: [i64; N] = p.map(|v| v.floor() as i64);
+    let frac: [f32; N] = from_fn(|i| p[i] - base[i] as f32);
+    let fade = frac.map(quintic);
+
+    (0..1usize << N)
+        .map(|bits| {
+            let step: [usize; N] = from_fn(|i| (bits >> i) & 1);
+            let cell = from_fn(|i| base[i] + step[i] as i64);
+            let offset = from_fn(|i| frac[i] - step[i] as f32);
+            let weight: f32 = (0..N)
+                .map(|i| if step[i] == 1 { fade[i] } else { 1.0 - fade[i] })
+                .product();
+            weight * corner(cell, offset)
+        })
+        .sum()
+}
+
+/// Ruido de gradientes de Perlin (versión mejorada, con interpolación quíntica)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Perlin {
+    pub seed: u64,
+}
+
+impl Perlin {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    fn sample<const N: usize>(&self, p: [f32; N], grad: fn(u64) -> [f32; N]) -> f32 {
+        lattice(p, |cell, offset| dot(grad(hash(self.seed, cell)), offset))
+    }
+}
+
+// Factores que llevan el máximo teórico de cada dimensión a ~1.0
+const PERLIN_SCALE: [f32; 4] = [2.0, std::f32::consts::SQRT_2, 1.0, 0.8];
+
+impl Noise for Perlin {
+    fn noise1(&self, x: f32) -> f32 {
+        (self.sample([x], grad1) * PERLIN_SCALE[0]).clamp(-1.0, 1.0)
+    }
+    fn noise2(&self, p: Vec2) -> f32 {
+        (self.sample(p.to_array(), grad2) * PERLIN_SCALE[1]).clamp(-1.0, 1.0)
+    }
+    fn noise3(&self, p: Vec3) -> f32 {
+        (self.sample(p.to_array(), grad3) * PERLIN_SCALE[2]).clamp(-1.0, 1.0)
+    }
+    fn noise4(&self, p: Vec4) -> f32 {
+        (self.sample(p.to_array(), grad4) * PERLIN_SCALE[3]).clamp(-1.0, 1.0)
+    }
+}
+
+/// Ruido de valores: valores al azar en los vértices de la cuadrícula, interpolados
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ValueNoise {
+    pub seed: u64,
+}
+
+impl ValueNoise {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    fn sample<const N: usize>(&self, p: [f32; N]) -> f32 {
+        lattice(p, |cell, _| unit(hash(self.seed, cell)) * 2.0 - 1.0)
+    }
+}
+
+impl Noise for ValueNoise {
+    fn noise1(&self, x: f32) -> f32 {
+        self.sample([x])
+    }
+    fn noise2(&self, p: Vec2) -> f32 {
+        self.sample(p.to_array())
+    }
+    fn noise3(&self, p: Vec3) -> f32 {
+        self.sample(p.to_array())
+    }
+    fn noise4(&self, p: Vec4) -> f32 {
+        self.sample(p.to_array())
+    }
+}
+
+/// Ruido simplex al estilo OpenSimplex2.
+///
+/// 2D y 4D suman núcleos `(0.5 - d²)⁴` en los vértices del simplex que contiene al
+/// punto; 3D usa el retículo BCC rotado de OpenSimplex2 (dos cuadrículas cúbicas
+/// desplazadas medio paso) con núcleos `(0.6 - d²)⁴`, sin artefactos alineados a los
+/// ejes. Los valores no coinciden bit a bit con la implementación de referencia.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Simplex {
+    pub seed: u64,
+}
+
+// Medidos: el máximo de cada dimensión queda en ~1.0
+const SIMPLEX_SCALE: [f32; 4] = [3.16, 101.0, 32.5, 62.0];
+// Segundo retículo del BCC con otra semilla
+const SEED_FLIP_3D: u64 = 0x7C5E_D2A1_7A2F_3B8D;
+
+impl Simplex {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Simplex clásico en N dimensiones
+    fn sample<const N: usize>(&self, p: [f32; N], grad: fn(u64) -> [f32; N]) -> f32 {
+        let n = N as f32;
+        let skew = ((n + 1.0).sqrt() - 1.0) / n;
+        let unskew = (1.0 - 1.0 / (n + 1.0).sqrt()) / n;
+
+        let s = p.iter().sum::<f32>() * skew;
+        let base: [i64; N] = p.map(|v| (v + s).floor() as i64);
+        let t = base.iter().sum::<i64>() as f32 * unskew;
+        let origin: [f32; N] = from_fn(|i| p[i] - (base[i] as f32 - t));
+
+        // Se recorren los vértices avanzando primero por el eje de mayor offset
+        let mut order: [usize; N] = from_fn(|i| i);
+        order.sort_by(|&a, &b| origin[b].total_cmp(&origin[a]));
+
+        let mut step = [0i64; N];
+        let mut total = 0.0;
+        for vertex in 0..=N {
+            if vertex > 0 {
+                step[order[vertex - 1]] += 1;
+            }
+            let offset: [f32; N] = from_fn(|i| origin[i] - step[i] as f32 + vertex as f32 * unskew);
+            let falloff = 0.5 - dot(offset, offset);
+            if falloff > 0.0 {
+                let cell: [i64; N] = from_fn(|i| base[i] + step[i]);
+                let falloff = falloff * falloff;
+                total += falloff * falloff * dot(grad(hash(self.seed, cell)), offset);
+            }
+        }
+        total
+    }
+
+    /// Retículo BCC de OpenSimplex2
+    fn sample_bcc(&self, p: Vec3) -> f32 {
+        // Rotación ortogonal que deja la diagonal principal en el eje vertical
+        let r = (2.0 / 3.0) * (p.x + p.y + p.z);
+        let rotated = Vec3::splat(r) - p;
+
+        let mut total = 0.0;
+        for (lattice, seed) in [(0.0, self.seed), (0.5, self.seed ^ SEED_FLIP_3D)] {
+            let local = rotated - Vec3::splat(lattice);
+            let base = local.floor();
+            for corner in 0..8 {
+                let step = Vec3::new(
+                    (corner & 1) as f32,
+                    ((corner >> 1) & 1) as f32,
+                    ((corner >> 2) & 1) as f32,
+                );
+                let offset = local - base - step;
+                let falloff = 0.6 - offset.length_squared();
+                if falloff > 0.0 {
+                    let cell = (base + step).to_array().map(|v| v as i64);
+                    let falloff = falloff * falloff;
+                    total += falloff * falloff * dot(grad3(hash(seed, cell)), offset.to_array());
+                }
+            }
+        }
+        total
+    }
+}
+
+impl Noise for Simplex {
+    fn noise1(&self, x: f32) -> f32 {
+        // Dos vecinos con núcleo (1 - d²)⁴
+        let base = x.floor();
+        let total: f32 = [0.0, 1.0]
+            .iter()
+            .map(|step| {
+                let offset = x - base - step;
+                let falloff = (1.0 - offset * offset).max(0.0);
+                let falloff = falloff * falloff;
+                let cell = [base as i64 + *step as i64];
+                falloff * falloff * grad1(hash(self.seed, cell))[0] * offset
+            })
+            .sum();
+        (total * SIMPLEX_SCALE[0]).clamp(-1.0, 1.0)
+    }
+    fn noise2(&self, p: Vec2) -> f32 {
+        (self.sample(p.to_array(), grad2_simplex) * SIMPLEX_SCALE[1]).clamp(-1.0, 1.0)
+    }
+    fn noise3(&self, p: Vec3) -> f32 {
+        (self.sample_bcc(p) * SIMPLEX_SCALE[2]).clamp(-1.0, 1.0)
+    }
+    fn noise4(&self, p: Vec4) -> f32 {
+        (self.sample(p.to_array(), grad4) * SIMPLEX_SCALE[3]).clamp(-1.0, 1.0)
+    }
+}
+
+/// Métrica para medir la distancia a los puntos de Worley
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WorleyDistance {
+    #[default]
+    Euclidean,
+    /// Sin raíz cuadrada: bordes más marcados y más rápido
+    EuclideanSquared,
+    Manhattan,
+    Chebyshev,
+}
+
+impl WorleyDistance {
+    fn measure<const N: usize>(self, delta: [f32; N]) -> f32 {
+        match self {
+            Self::Euclidean => dot(delta, delta).sqrt(),
+            Self::EuclideanSquared => dot(delta, delta),
+            Self::Manhattan => delta.iter().map(|d| d.abs()).sum(),
+            Self::Chebyshev => delta.iter().fold(0.0, |max, d| d.abs().max(max)),
+        }
+    }
+}
+
+/// Qué valor devuelve `Worley`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WorleyOutput {
+    /// Distancia al punto más cercano
+    #[default]
+    F1,
+    /// Distancia al segundo punto más cercano
+    F2,
+    /// `F2 - F1`: cero en los bordes entre celdas
+    F2MinusF1,
+    /// Valor constante por celda (-1.0 .. 1.0), un mosaico de Voronoi
+    CellValue,
+}
+
+/// Ruido celular de Worley: un punto al azar por celda y la distancia a los más cercanos
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Worley {
+    pub seed: u64,
+    pub distance: WorleyDistance,
+    pub output: WorleyOutput,
+    /// Cuánto se aleja el punto del centro de su celda (0.0 .. 1.0)
+    pub jitter: f32,
+}
+
+impl Default for Worley {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Worley {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            distance: WorleyDistance::default(),
+            output: WorleyOutput::default(),
+            jitter: 1.0,
+        }
+    }
+
+    pub fn with_distance(mut self, distance: WorleyDistance) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    pub fn with_output(mut self, output: WorleyOutput) -> Self {
+        self.output = output;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: f32) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Distancias F1 y F2 buscando en las 3^N celdas vecinas
+    pub fn distances<const N: usize>(&self, p: [f32; N]) -> (f32, f32) {
+        self.search(p).0
+    }
+
+    fn search<const N: usize>(&self, p: [f32; N]) -> ((f32, f32), [i64; N]) {
+        let base: [i64; N] = p.map(|v| v.floor() as i64);
+        let frac: [f32; N] = from_fn(|i| p[i] - base[i] as f32);
+
+        let (mut f1, mut f2) = (f32::INFINITY, f32::INFINITY);
+        let mut nearest = base;
+        for neighbor in 0..3usize.pow(N as u32) {
+            let step: [i64; N] = from_fn(|i| (neighbor / 3usize.pow(i as u32) % 3) as i64 - 1);
+            let cell = from_fn(|i| base[i] + step[i]);
+            let h = hash(self.seed, cell);
+            let delta: [f32; N] = from_fn(|i| {
+                let point = 0.5 + (unit(rehash(h, i)) - 0.5) * self.jitter;
+                step[i] as f32 + point - frac[i]
+            });
+            let distance = self.distance.measure(delta);
+            if distance < f1 {
+                (f1, f2) = (distance, f1);
+                nearest = cell;
+            } else if distance < f2 {
+                f2 = distance;
+            }
+        }
+        ((f1, f2), nearest)
+    }
+
+    fn sample<const N: usize>(&self, p: [f32; N]) -> f32 {
+        let ((f1, f2), nearest) = self.search(p);
+        match self.output {
+            WorleyOutput::F1 => f1,
+            WorleyOutput::F2 => f2,
+            WorleyOutput::F2MinusF1 => f2 - f1,
+            WorleyOutput::CellValue => unit(rehash(hash(self.seed, nearest), N)) * 2.0 - 1.0,
+        }
+    }
+}
+
+impl Noise for Worley {
+    fn noise1(&self, x: f32) -> f32 {
+        self.sample([x])
+    }
+    fn noise2(&self, p: Vec2) -> f32 {
+        self.sample(p.to_array())
+    }
+    fn noise3(&self, p: Vec3) -> f32 {
+        self.sample(p.to_array())
+    }
+    fn noise4(&self, p: Vec4) -> f32 {
+        self.sample(p.to_array())
+    }
+}
+
+/// Cómo se combinan las octavas de `Fractal`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FractalKind {
+    /// Suma de octavas (fractional Brownian motion)
+    #[default]
+    Fbm,
+    /// `(1 - |n|)²` ponderado por la octava anterior: crestas de montaña
+    Ridged,
+    /// `2|n| - 1`: nubes y formas redondeadas
+    Billow,
+}
+
+// Desplazamiento entre octavas para que no coincidan sus ceros en el origen
+const OCTAVE_SHIFT: f32 = 17.31;
+
+/// Suma octavas de `source` con frecuencia creciente y amplitud decreciente.
+/// Con fuentes en -1.0 .. 1.0 el resultado también queda en ese rango.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fractal<N> {
+    pub source: N,
+    pub kind: FractalKind,
+    pub octaves: u32,
+    /// Frecuencia de la primera octava
+    pub frequency: f32,
+    /// Multiplicador de frecuencia entre octavas
+    pub lacunarity: f32,
+    /// Multiplicador de amplitud entre octavas
+    pub gain: f32,
+}
+
+impl<N: Noise> Fractal<N> {
+    pub fn new(source: N, kind: FractalKind) -> Self {
+        Self {
+            source,
+            kind,
+            octaves: 5,
+            frequency: 1.0,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+
+    pub fn fbm(source: N) -> Self {
+        Self::new(source, FractalKind::Fbm)
+    }
+
+    pub fn ridged(source: N) -> Self {
+        Self::new(source, FractalKind::Ridged)
+    }
+
+    pub fn billow(source: N) -> Self {
+        Self::new(source, FractalKind::Billow)
+    }
+
+    pub fn with_octaves(mut self, octaves: u32) -> Self {
+        self.octaves = octaves.max(1);
+        self
+    }
+
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    pub fn with_lacunarity(mut self, lacunarity: f32) -> Self {
+        self.lacunarity = lacunarity;
+        self
+    }
+
+    pub fn with_gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    /// `sample(frecuencia, desplazamiento)` evalúa una octava
+    fn accumulate(&self, sample: impl Fn(f32, f32) -> f32) -> f32 {
+        let mut frequency = self.frequency;
+        let mut amplitude = 1.0;
+        let mut weight = 1.0;
+        let (mut total, mut norm) = (0.0, 0.0);
+
+        for octave in 0..self.octaves.max(1) {
+            let n = sample(frequency, octave as f32 * OCTAVE_SHIFT);
+            let value = match self.kind {
+                FractalKind::Fbm => n,
+                FractalKind::Billow => n.abs() * 2.0 - 1.0,
+                FractalKind::Ridged => {
+                    let ridge = (1.0 - n.abs()).powi(2) * weight;
+                    weight = (ridge * 2.0).clamp(0.0, 1.0);
+                    ridge
+                }
+            };
+            total += value * amplitude;
+            norm += amplitude;
+            amplitude *= self.gain;
+            frequency *= self.lacunarity;
+        }
+
+        let value = total / norm.max(f32::EPSILON);
+        match self.kind {
+            FractalKind::Ridged => value * 2.0 - 1.0,
+            _ => value,
+        }
+    }
+}
+
+impl<N: Noise> Noise for Fractal<N> {
+    fn noise1(&self, x: f32) -> f32 {
+        self.accumulate(|frequency, shift| self.source.noise1(x * frequency + shift))
+    }
+    fn noise2(&self, p: Vec2) -> f32 {
+        self.accumulate(|frequency, shift| self.source.noise2(p * frequency + shift))
+    }
+    fn noise3(&self, p: Vec3) -> f32 {
+        self.accumulate(|frequency, shift| self.source.noise3(p * frequency + shift))
+    }
+    fn noise4(&self, p: Vec4) -> f32 {
+        self.accumulate(|frequency, shift| self.source.noise4(p * frequency + shift))
+    }
+}
+
+// Desplazamiento entre los ejes del warp para que no sean la misma señal
+const WARP_SHIFT: f32 = 31.7;
+
+/// Deforma el espacio antes de muestrear: `source(p + amplitude * warp(p * frequency))`,
+/// con una componente de `warp` por eje
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DomainWarp<N, W> {
+    pub source: N,
+    pub warp: W,
+    /// Desplazamiento máximo, en unidades de entrada de `source`
+    pub amplitude: f32,
+    /// Frecuencia con la que se muestrea `warp`
+    pub frequency: f32,
+}
+
+impl<N: Noise, W: Noise> DomainWarp<N, W> {
+    pub fn new(source: N, warp: W) -> Self {
+        Self {
+            source,
+            warp,
+            amplitude: 1.0,
+            frequency: 1.0,
+        }
+    }
+
+    pub fn with_amplitude(mut self, amplitude: f32) -> Self {
+        self.amplitude = amplitude;
+        self
+    }
+
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+}
+
+impl<N: Noise, W: Noise> Noise for DomainWarp<N, W> {
+    fn noise1(&self, x: f32) -> f32 {
+        let offset = self.warp.noise1(x * self.frequency);
+        self.source.noise1(x + offset * self.amplitude)
+    }
+    fn noise2(&self, p: Vec2) -> f32 {
+        let q = p * self.frequency;
+        let offset = Vec2::new(self.warp.noise2(q), self.warp.noise2(q + WARP_SHIFT));
+        self.source.noise2(p + offset * self.amplitude)
+    }
+    fn noise3(&self, p: Vec3) -> f32 {
+        let q = p * self.frequency;
+        let offset = Vec3::new(
+            self.warp.noise3(q),
+            self.warp.noise3(q + WARP_SHIFT),
+            self.warp.noise3(q + 2.0 * WARP_SHIFT),
+        );
+        self.source.noise3(p + offset * self.amplitude)
+    }
+    fn noise4(&self, p: Vec4) -> f32 {
+        let q = p * self.frequency;
+        let offset = Vec4::new(
+            self.warp.noise4(q),
+            self.warp.noise4(q + WARP_SHIFT),
+            self.warp.noise4(q + 2.0 * WARP_SHIFT),
+            self.warp.noise4(q + 3.0 * WARP_SHIFT),
+        );
+        self.source.noise4(p + offset * self.amplitude)
+    }
+}
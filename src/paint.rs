@@ -0,0 +1,155 @@
+use glam::Vec2;
+
+use super::{colores::Color, gradient::Gradient};
+
+// Resolución de la tabla de colores precalculada de cada Paint
+const PAINT_LUT_SIZE: usize = 256;
+
+/// Geometría del degradado
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PaintKind {
+    /// De `start` (t = 0) a `end` (t = 1)
+    Linear { start: Vec2, end: Vec2 },
+    /// Círculos de `focal` (t = 0) hasta el círculo `center`/`radius` (t = 1)
+    Radial {
+        center: Vec2,
+        radius: f32,
+        focal: Vec2,
+    },
+    /// Barrido angular alrededor de `center`, empezando en `angle` (radianes)
+    Conic { center: Vec2, angle: f32 },
+}
+
+/// Sistema de coordenadas en el que se definen los puntos del Paint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaintSpace {
+    /// Coordenadas de los vértices del shape: el degradado sigue a su Transform
+    #[default]
+    Local,
+    /// Píxeles del canvas: el shape se mueve sobre un degradado fijo
+    Screen,
+}
+
+/// Relleno con degradado para un `Material`
+#[derive(Debug, Clone)]
+pub struct Paint {
+    pub kind: PaintKind,
+    pub space: PaintSpace,
+    gradient: Gradient,
+    lut: Vec<u32>,
+}
+
+impl Paint {
+    pub fn new(kind: PaintKind, gradient: Gradient) -> Self {
+        let lut = gradient
+            .lookup_table(PAINT_LUT_SIZE)
+            .iter()
+            .map(Color::to_hex)
+            .collect();
+        Self {
+            kind,
+            space: PaintSpace::Local,
+            gradient,
+            lut,
+        }
+    }
+
+    pub fn linear(start: Vec2, end: Vec2, gradient: Gradient) -> Self {
+        Self::new(PaintKind::Linear { start, end }, gradient)
+    }
+
+    /// Radial con el foco en el centro
+    pub fn radial(center: Vec2, radius: f32, gradient: Gradient) -> Self {
+        let focal = center;
+        Self::new(
+            PaintKind::Radial {
+                center,
+                radius,
+                focal,
+            },
+            gradient,
+        )
+    }
+
+    pub fn conic(center: Vec2, angle: f32, gradient: Gradient) -> Self {
+        Self::new(PaintKind::Conic { center, angle }, gradient)
+    }
+
+    /// Mueve el foco de un degradado radial; se mantiene dentro del círculo
+    pub fn with_focal(mut self, point: Vec2) -> Self {
+        if let PaintKind::Radial { focal, .. } = &mut self.kind {
+            *focal = point;
+        }
+        self
+    }
+
+    pub fn with_space(mut self, space: PaintSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    pub fn gradient(&self) -> &Gradient {
+        &self.gradient
+    }
+
+    pub fn set_gradient(&mut self, gradient: Gradient) {
+        *self = Self {
+            kind: self.kind,
+            space: self.space,
+            ..Self::new(self.kind, gradient)
+        };
+    }
+
+    /// Posición `t` en el degradado, antes de aplicar el `Spread`
+    pub fn offset_at(&self, point: Vec2) -> f32 {
+        match self.kind {
+            PaintKind::Linear { start, end } => {
+                let axis = end - start;
+                let length2 = axis.length_squared();
+                if length2 <= f32::EPSILON {
+                    return 0.0;
+                }
+                (point - start).dot(axis) / length2
+            }
+            PaintKind::Radial {
+                center,
+                radius,
+                focal,
+            } => radial_offset(point, center, radius, focal),
+            PaintKind::Conic { center, angle } => {
+                let delta = point - center;
+                (delta.y.atan2(delta.x) - angle).rem_euclid(std::f32::consts::TAU)
+                    / std::f32::consts::TAU
+            }
+        }
+    }
+
+    /// Color AARRGGBB en `point` (en el espacio del Paint)
+    pub fn color_at(&self, point: Vec2) -> u32 {
+        let t = self.gradient.spread.apply(self.offset_at(point));
+        let index = (t * (PAINT_LUT_SIZE - 1) as f32).round() as usize;
+        self.lut[index.min(PAINT_LUT_SIZE - 1)]
+    }
+}
+
+// Degradado de dos círculos (como SVG): el círculo de radio 0 en el foco
+// crece hasta `center`/`radius`. Se busca el t >= 0 cuyo círculo pasa por `point`.
+fn radial_offset(point: Vec2, center: Vec2, radius: f32, focal: Vec2) -> f32 {
+    if radius <= f32::EPSILON {
+        return 0.0;
+    }
+
+    // Foco ligeramente dentro del círculo para que la ecuación tenga una raíz positiva
+    let mut focal_offset = focal - center;
+    let max_offset = radius * 0.99;
+    if focal_offset.length() > max_offset {
+        focal_offset = focal_offset.normalize() * max_offset;
+    }
+
+    let d = -focal_offset; // De foco a centro
+    let e = point - (center + focal_offset);
+    let a = d.length_squared() - radius * radius; // < 0
+    let b = e.dot(d);
+    let discriminant = (b * b - a * e.length_squared()).max(0.0);
+    (b - discriminant.sqrt()) / a
+}
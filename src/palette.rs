@@ -0,0 +1,708 @@
+use std::sync::OnceLock;
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use super::{
+    colores::Color,
+    postprocess::{PostEffect, unpack_rgb},
+    texture::Texture,
+};
+
+/// Lista ordenada de colores para cuantizar imágenes (looks retro, exportar GIF)
+#[derive(Debug, Clone, Default)]
+pub struct Palette {
+    colors: Vec<Color>,
+}
+
+impl Palette {
+    pub fn new(colors: Vec<Color>) -> Self {
+        Self { colors }
+    }
+
+    /// Paleta de 4 verdes de la Game Boy original
+    pub fn gameboy() -> Self {
+        Self::from_hex_values(&[0x0F380F, 0x306230, 0x8BAC0F, 0x9BBC0F])
+    }
+
+    /// Paleta de 16 colores de PICO-8
+    pub fn pico8() -> Self {
+        Self::from_hex_values(&[
+            0x000000, 0x1D2B53, 0x7E2553, 0x008751, 0xAB5236, 0x5F574F, 0xC2C3C7, 0xFFF1E8,
+            0xFF004D, 0xFFA300, 0xFFEC27, 0x00E436, 0x29ADFF, 0x83769C, 0xFF77A8, 0xFFCCAA,
+        ])
+    }
+
+    fn from_hex_values(values: &[u32]) -> Self {
+        Self::new(
+            values
+                .iter()
+                .map(|&value| Color::rgb((value >> 16) as u8, (value >> 8) as u8, value as u8))
+                .collect(),
+        )
+    }
+
+    /// Lee una paleta `.hex` (RRGGBB por línea), `.gpl` (GIMP) o `.pal` (JASC o RIFF)
+    pub fn load(path: &str) -> Result<Self, String> {
+        let bytes =
+            std::fs::read(path).map_err(|e| format!("Error loading palette {}: {}", path, e))?;
+        let extension = std::path::Path::new(path)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        let result = if bytes.starts_with(b"RIFF") {
+            Self::parse_riff(&bytes)
+        } else {
+            let text = String::from_utf8_lossy(&bytes);
+            match extension.as_str() {
+                "hex" => Self::parse_hex(&text),
+                "gpl" => Self::parse_gpl(&text),
+                "pal" => Self::parse_jasc(&text),
+                _ => Err(format!("unknown palette format '.{}'", extension)),
+            }
+        };
+        result.map_err(|e| format!("Error parsing palette {}: {}", path, e))
+    }
+
+    /// Un color `RRGGBB` por línea, con o sin `#`
+    pub fn parse_hex(text: &str) -> Result<Self, String> {
+        let mut colors = Vec::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim().trim_start_matches('#');
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            let value = u32::from_str_radix(line, 16)
+                .ok()
+                .filter(|_| line.len() == 6)
+                .ok_or_else(|| format!("line {}: expected RRGGBB", number + 1))?;
+            colors.push(Color::rgb(
+                (value >> 16) as u8,
+                (value >> 8) as u8,
+                value as u8,
+            ));
+        }
+        Self::non_empty(colors)
+    }
+
+    /// Formato de paletas de GIMP
+    pub fn parse_gpl(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines().enumerate();
+        if lines
+            .next()
+            .is_none_or(|(_, line)| line.trim() != "GIMP Palette")
+        {
+            return Err("missing 'GIMP Palette' header".to_string());
+        }
+
+        let mut colors = Vec::new();
+        for (number, line) in lines {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.contains(':') {
+                continue;
+            }
+            colors.push(parse_rgb_line(line, number)?);
+        }
+        Self::non_empty(colors)
+    }
+
+    /// Formato de texto JASC-PAL (Paint Shop Pro)
+    pub fn parse_jasc(text: &str) -> Result<Self, String> {
+        let mut lines = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .enumerate();
+        if lines.next().is_none_or(|(_, line)| line != "JASC-PAL") {
+            return Err("missing 'JASC-PAL' header".to_string());
+        }
+        lines.next(); // Versión
+
+        let count: usize = lines
+            .next()
+            .and_then(|(_, line)| line.parse().ok())
+            .ok_or("missing color count")?;
+
+        let colors = lines
+            .take(count)
+            .map(|(number, line)| parse_rgb_line(line, number))
+            .collect::<Result<Vec<_>, _>>()?;
+        if colors.len() != count {
+            return Err(format!("expected {} colors, found {}", count, colors.len()));
+        }
+        Self::non_empty(colors)
+    }
+
+    /// Paleta binaria de Windows (`RIFF` ... `PAL data`)
+    pub fn parse_riff(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < 24 || &bytes[8..12] != b"PAL " || &bytes[12..16] != b"data" {
+            return Err("not a RIFF palette".to_string());
+        }
+        let count = u16::from_le_bytes([bytes[22], bytes[23]]) as usize;
+        let entries = bytes
+            .get(24..24 + count * 4)
+            .ok_or("truncated RIFF palette")?;
+        let colors = entries
+            .chunks_exact(4)
+            .map(|entry| Color::rgb(entry[0], entry[1], entry[2]))
+            .collect();
+        Self::non_empty(colors)
+    }
+
+    fn non_empty(colors: Vec<Color>) -> Result<Self, String> {
+        if colors.is_empty() {
+            Err("palette has no colors".to_string())
+        } else {
+            Ok(Self::new(colors))
+        }
+    }
+
+    /// Paleta de hasta `count` colores por corte de mediana sobre píxeles AARRGGBB.
+    /// Los píxeles totalmente transparentes se ignoran.
+    pub fn median_cut(pixels: &[u32], count: usize) -> Self {
+        let samples: Vec<[u8; 3]> = pixels
+            .iter()
+            .filter(|&&pixel| pixel >> 24 != 0)
+            .map(|&pixel| [(pixel >> 16) as u8, (pixel >> 8) as u8, pixel as u8])
+            .collect();
+        if samples.is_empty() || count == 0 {
+            return Self::default();
+        }
+
+        let mut boxes = vec![samples];
+        while boxes.len() < count {
+            // Se divide la caja con el canal de mayor rango
+            let Some((index, channel, _)) = boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, samples)| samples.len() > 1)
+                .map(|(index, samples)| {
+                    let (channel, range) = widest_channel(samples);
+                    (index, channel, range)
+                })
+                .filter(|&(_, _, range)| range > 0)
+                .max_by_key(|&(_, _, range)| range)
+            else {
+                break;
+            };
+
+            let mut samples = boxes.swap_remove(index);
+            samples.sort_unstable_by_key(|sample| sample[channel]);
+            let upper = samples.split_off(samples.len() / 2);
+            boxes.push(samples);
+            boxes.push(upper);
+        }
+
+        let colors = boxes
+            .iter()
+            .map(|samples| {
+                let mut sum = [0u64; 3];
+                for sample in samples {
+                    for (acc, &value) in sum.iter_mut().zip(sample) {
+                        *acc += value as u64;
+                    }
+                }
+                let n = samples.len() as u64;
+                Color::rgb(
+                    ((sum[0] + n / 2) / n) as u8,
+                    ((sum[1] + n / 2) / n) as u8,
+                    ((sum[2] + n / 2) / n) as u8,
+                )
+            })
+            .collect();
+        Self::new(colors)
+    }
+
+    pub fn from_image(image: &image::RgbaImage, count: usize) -> Self {
+        Self::median_cut(&Texture::from_image(image).data, count)
+    }
+
+    pub fn from_texture(texture: &Texture, count: usize) -> Self {
+        Self::median_cut(&texture.data, count)
+    }
+
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    pub fn get(&self, index: usize) -> Option<Color> {
+        self.colors.get(index).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Índice del color más cercano (distancia euclídea en RGB)
+    pub fn nearest_index(&self, color: Color) -> Option<usize> {
+        self.nearest_rgb([color.r, color.g, color.b].map(|value| value as f32))
+    }
+
+    pub fn nearest(&self, color: Color) -> Option<Color> {
+        self.nearest_index(color).map(|index| self.colors[index])
+    }
+
+    fn nearest_rgb(&self, rgb: [f32; 3]) -> Option<usize> {
+        self.colors
+            .iter()
+            .map(|color| {
+                let dr = color.r as f32 - rgb[0];
+                let dg = color.g as f32 - rgb[1];
+                let db = color.b as f32 - rgb[2];
+                dr * dr + dg * dg + db * db
+            })
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index)
+    }
+
+    /// Copia de la textura reducida a esta paleta
+    pub fn quantize_texture(&self, texture: &Texture, dither: Dither) -> Texture {
+        Quantize::new(self.clone(), dither).apply_texture(texture)
+    }
+}
+
+fn parse_rgb_line(line: &str, number: usize) -> Result<Color, String> {
+    let mut values = line.split_whitespace().map(|word| word.parse::<u8>());
+    let mut next = || {
+        values
+            .next()
+            .and_then(|value| value.ok())
+            .ok_or_else(|| format!("line {}: expected 'r g b'", number + 1))
+    };
+    Ok(Color::rgb(next()?, next()?, next()?))
+}
+
+fn widest_channel(samples: &[[u8; 3]]) -> (usize, u8) {
+    let mut min = [u8::MAX; 3];
+    let mut max = [0u8; 3];
+    for sample in samples {
+        for channel in 0..3 {
+            min[channel] = min[channel].min(sample[channel]);
+            max[channel] = max[channel].max(sample[channel]);
+        }
+    }
+    (0..3)
+        .map(|channel| (channel, max[channel] - min[channel]))
+        .max_by_key(|&(_, range)| range)
+        .unwrap_or((0, 0))
+}
+
+/// Forma de repartir el error al reducir colores
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dither {
+    /// Color más cercano, sin tramado
+    #[default]
+    None,
+    /// Tramado ordenado con matriz de Bayer de 2, 4 u 8
+    Bayer(usize),
+    /// Difusión de error Floyd–Steinberg
+    FloydSteinberg,
+    /// Difusión de error Atkinson: solo reparte 3/4 del error, más contraste
+    Atkinson,
+    /// Umbral con ruido azul (void-and-cluster de 64x64)
+    BlueNoise,
+}
+
+// Bits por canal de la caché de colores más cercanos
+const CACHE_BITS: u32 = 6;
+const CACHE_UNSET: u16 = u16::MAX;
+
+/// Etapa de post-proceso que reduce el frame a una paleta
+#[derive(Debug, Clone)]
+pub struct Quantize {
+    palette: Palette,
+    pub dither: Dither,
+    /// Fuerza del tramado ordenado (Bayer y ruido azul), 1.0 por defecto
+    pub strength: f32,
+    cache: Vec<u16>,
+    errors: Vec<[f32; 3]>,
+}
+
+impl Quantize {
+    pub fn new(palette: Palette, dither: Dither) -> Self {
+        Self {
+            palette,
+            dither,
+            strength: 1.0,
+            cache: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn with_strength(mut self, strength: f32) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    pub fn palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+        self.cache.clear();
+    }
+
+    /// Copia de la textura reducida a la paleta; el alfa se conserva
+    pub fn apply_texture(&mut self, texture: &Texture) -> Texture {
+        let (width, height) = texture.size;
+        let mut data = vec![0; texture.data.len()];
+        self.apply(&texture.data, &mut data, width as usize, height as usize);
+        Texture::new(width, height, data)
+    }
+
+    fn nearest(&mut self, rgb: [f32; 3]) -> u32 {
+        if self.cache.is_empty() {
+            self.cache.resize(1 << (3 * CACHE_BITS), CACHE_UNSET);
+        }
+        let shift = 8 - CACHE_BITS;
+        let [r, g, b] = rgb.map(|value| value.round().clamp(0.0, 255.0) as u32 >> shift);
+        let key = (r << (2 * CACHE_BITS) | g << CACHE_BITS | b) as usize;
+
+        if self.cache[key] == CACHE_UNSET {
+            // Se busca con el centro de la celda de la caché
+            let center = [r, g, b].map(|value| ((value << shift) + (1 << shift) / 2) as f32);
+            self.cache[key] = self.palette.nearest_rgb(center).unwrap_or(0) as u16;
+        }
+        self.palette.colors[self.cache[key] as usize].to_hex() & 0x00FF_FFFF
+    }
+
+    fn ordered(
+        &mut self,
+        src: &[u32],
+        dst: &mut [u32],
+        width: usize,
+        threshold: impl Fn(usize, usize) -> f32,
+    ) {
+        // El paso típico entre colores de la paleta, repartido en los tres canales
+        let spread = 255.0 / (self.palette.len() as f32).cbrt().max(1.0) * self.strength;
+        for (index, (out, &pixel)) in dst.iter_mut().zip(src).enumerate() {
+            let offset = threshold(index % width, index / width) * spread;
+            let rgb = unpack_rgb(pixel).map(|value| value + offset);
+            *out = (pixel & 0xFF00_0000) | self.nearest(rgb);
+        }
+    }
+
+    fn diffuse(
+        &mut self,
+        src: &[u32],
+        dst: &mut [u32],
+        width: usize,
+        height: usize,
+        taps: &[(isize, isize, f32)],
+    ) {
+        self.errors.clear();
+        self.errors.resize(width * height, [0.0; 3]);
+
+        for y in 0..height {
+            for x in 0..width {
+                let index = y * width + x;
+                let wanted = unpack_rgb(src[index]);
+                let mut rgb = wanted;
+                for (value, error) in rgb.iter_mut().zip(self.errors[index]) {
+                    *value += error;
+                }
+
+                let chosen = self.nearest(rgb);
+                dst[index] = (src[index] & 0xFF00_0000) | chosen;
+
+                let got = unpack_rgb(chosen);
+                let error: [f32; 3] = std::array::from_fn(|i| rgb[i] - got[i]);
+                for &(dx, dy, weight) in taps {
+                    let (nx, ny) = (x as isize + dx, y as isize + dy);
+                    if nx < 0 || nx >= width as isize || ny >= height as isize {
+                        continue;
+                    }
+                    let target = &mut self.errors[ny as usize * width + nx as usize];
+                    for (acc, value) in target.iter_mut().zip(error) {
+                        *acc += value * weight;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl PostEffect for Quantize {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        if self.palette.is_empty() {
+            dst.copy_from_slice(src);
+            return;
+        }
+
+        match self.dither {
+            Dither::None => {
+                for (out, &pixel) in dst.iter_mut().zip(src) {
+                    *out = (pixel & 0xFF00_0000) | self.nearest(unpack_rgb(pixel));
+                }
+            }
+            Dither::Bayer(size) => {
+                let matrix = bayer_matrix(size);
+                let n = matrix.len().isqrt();
+                let levels = (n * n) as f32;
+                self.ordered(src, dst, width, |x, y| {
+                    (matrix[(y % n) * n + x % n] as f32 + 0.5) / levels - 0.5
+                });
+            }
+            Dither::BlueNoise => {
+                let noise = blue_noise();
+                self.ordered(src, dst, width, |x, y| {
+                    let rank = noise[(y % BLUE_NOISE_SIZE) * BLUE_NOISE_SIZE + x % BLUE_NOISE_SIZE];
+                    (rank as f32 + 0.5) / (BLUE_NOISE_SIZE * BLUE_NOISE_SIZE) as f32 - 0.5
+                });
+            }
+            Dither::FloydSteinberg => self.diffuse(
+                src,
+                dst,
+                width,
+                height,
+                &[
+                    (1, 0, 7.0 / 16.0),
+                    (-1, 1, 3.0 / 16.0),
+                    (0, 1, 5.0 / 16.0),
+                    (1, 1, 1.0 / 16.0),
+                ],
+            ),
+            Dither::Atkinson => self.diffuse(
+                src,
+                dst,
+                width,
+                height,
+                &[
+                    (1, 0, 0.125),
+                    (2, 0, 0.125),
+                    (-1, 1, 0.125),
+                    (0, 1, 0.125),
+                    (1, 1, 0.125),
+                    (0, 2, 0.125),
+                ],
+            ),
+        }
+    }
+    fn name(&self) -> &str {
+        "Quantize"
+    }
+}
+
+/// Matriz de Bayer recursiva de lado 2, 4 u 8 (otros tamaños se redondean)
+fn bayer_matrix(size: usize) -> Vec<u32> {
+    let size = size.clamp(2, 8).next_power_of_two();
+    let mut matrix = vec![0u32];
+    let mut n = 1;
+    while n < size {
+        let mut next = vec![0u32; 4 * n * n];
+        for y in 0..n {
+            for x in 0..n {
+                let value = 4 * matrix[y * n + x];
+                next[y * 2 * n + x] = value;
+                next[y * 2 * n + x + n] = value + 2;
+                next[(y + n) * 2 * n + x] = value + 3;
+                next[(y + n) * 2 * n + x + n] = value + 1;
+            }
+        }
+        matrix = next;
+        n *= 2;
+    }
+    matrix
+}
+
+const BLUE_NOISE_SIZE: usize = 64;
+
+/// Rangos 0 .. 64² con distribución de ruido azul, generados una vez
+fn blue_noise() -> &'static [u32] {
+    static NOISE: OnceLock<Vec<u32>> = OnceLock::new();
+    NOISE.get_or_init(|| void_and_cluster(BLUE_NOISE_SIZE, 1.5, 0x6A1A))
+}
+
+/// Algoritmo void-and-cluster de Ulichney sobre un toro de `size`x`size`
+fn void_and_cluster(size: usize, sigma: f32, seed: u64) -> Vec<u32> {
+    let total = size * size;
+
+    // Kernel gaussiano toroidal indexado por desplazamiento
+    let kernel: Vec<f32> = (0..total)
+        .map(|i| {
+            let dx = (i % size).min(size - i % size) as f32;
+            let dy = (i / size).min(size - i / size) as f32;
+            (-(dx * dx + dy * dy) / (2.0 * sigma * sigma)).exp()
+        })
+        .collect();
+
+    let update = |energy: &mut [f32], point: usize, sign: f32| {
+        let (px, py) = (point % size, point / size);
+        for (i, value) in energy.iter_mut().enumerate() {
+            let dx = (i % size + size - px) % size;
+            let dy = (i / size + size - py) % size;
+            *value += sign * kernel[dy * size + dx];
+        }
+    };
+    let tightest_cluster = |pattern: &[bool], energy: &[f32]| {
+        (0..total)
+            .filter(|&i| pattern[i])
+            .max_by(|&a, &b| energy[a].total_cmp(&energy[b]))
+            .unwrap_or(0)
+    };
+    let largest_void = |pattern: &[bool], energy: &[f32]| {
+        (0..total)
+            .filter(|&i| !pattern[i])
+            .min_by(|&a, &b| energy[a].total_cmp(&energy[b]))
+            .unwrap_or(0)
+    };
+
+    // Patrón inicial: ~10% de puntos al azar, luego se reparten uniformemente
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut pattern = vec![false; total];
+    let mut energy = vec![0.0f32; total];
+    let ones = total / 10;
+    let mut placed = 0;
+    while placed < ones {
+        let point = rng.random_range(0..total);
+        if !pattern[point] {
+            pattern[point] = true;
+            update(&mut energy, point, 1.0);
+            placed += 1;
+        }
+    }
+    // Suele converger enseguida, pero nada lo garantiza: se limita a `total` intercambios
+    for _ in 0..total {
+        let cluster = tightest_cluster(&pattern, &energy);
+        pattern[cluster] = false;
+        update(&mut energy, cluster, -1.0);
+        let void = largest_void(&pattern, &energy);
+        pattern[void] = true;
+        update(&mut energy, void, 1.0);
+        if void == cluster {
+            break;
+        }
+    }
+
+    let mut ranks = vec![0u32; total];
+
+    // Fase 1: quitar puntos del prototipo, de los más agrupados hacia abajo
+    let mut prototype = pattern.clone();
+    let mut prototype_energy = energy.clone();
+    for rank in (0..ones).rev() {
+        let cluster = tightest_cluster(&prototype, &prototype_energy);
+        prototype[cluster] = false;
+        update(&mut prototype_energy, cluster, -1.0);
+        ranks[cluster] = rank as u32;
+    }
+
+    // Fases 2 y 3: llenar los huecos más grandes hasta completar
+    for rank in ones..total {
+        let void = largest_void(&pattern, &energy);
+        pattern[void] = true;
+        update(&mut energy, void, 1.0);
+        ranks[void] = rank as u32;
+    }
+
+    ranks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgb(palette: &Palette) -> Vec<[u8; 3]> {
+        palette.colors().iter().map(|c| [c.r, c.g, c.b]).collect()
+    }
+
+    #[test]
+    fn parse_hex() {
+        let palette = Palette::parse_hex("; comentario\nff8000\n\n#00FF7f\r\n").unwrap();
+        assert_eq!(rgb(&palette), [[255, 128, 0], [0, 255, 127]]);
+        assert!(
+            Palette::parse_hex("ff8000\nfff")
+                .unwrap_err()
+                .contains("line 2")
+        );
+        assert!(Palette::parse_hex("ff80zz").is_err());
+        assert!(
+            Palette::parse_hex("; vacía")
+                .unwrap_err()
+                .contains("no colors")
+        );
+    }
+
+    #[test]
+    fn parse_gpl() {
+        let text =
+            "GIMP Palette\nName: Prueba\nColumns: 2\n#\n255   0   0\tRojo\n  0 128 255 Azul\n";
+        let palette = Palette::parse_gpl(text).unwrap();
+        assert_eq!(rgb(&palette), [[255, 0, 0], [0, 128, 255]]);
+        assert!(
+            Palette::parse_gpl("255 0 0")
+                .unwrap_err()
+                .contains("header")
+        );
+        let bad = "GIMP Palette\n255 0\n";
+        assert!(Palette::parse_gpl(bad).unwrap_err().contains("line 2"));
+        let out_of_range = "GIMP Palette\n256 0 0\n";
+        assert!(Palette::parse_gpl(out_of_range).is_err());
+    }
+
+    #[test]
+    fn parse_jasc() {
+        let text = "JASC-PAL\r\n0100\r\n3\r\n0 0 0\r\n255 255 255\r\n10 20 30\r\n";
+        let palette = Palette::parse_jasc(text).unwrap();
+        assert_eq!(rgb(&palette), [[0, 0, 0], [255, 255, 255], [10, 20, 30]]);
+        let short = "JASC-PAL\n0100\n3\n0 0 0\n";
+        assert!(
+            Palette::parse_jasc(short)
+                .unwrap_err()
+                .contains("expected 3")
+        );
+        assert!(Palette::parse_jasc("JASC-PAL\n0100\n").is_err());
+        assert!(Palette::parse_jasc("GIMP Palette\n").is_err());
+    }
+
+    #[test]
+    fn parse_riff() {
+        let mut bytes = b"RIFF\0\0\0\0PAL data\0\0\0\0\x00\x03\x02\x00".to_vec();
+        bytes.extend_from_slice(&[255, 0, 0, 0, 1, 2, 3, 0]);
+        let palette = Palette::parse_riff(&bytes).unwrap();
+        assert_eq!(rgb(&palette), [[255, 0, 0], [1, 2, 3]]);
+        assert!(
+            Palette::parse_riff(&bytes[..28])
+                .unwrap_err()
+                .contains("truncated")
+        );
+        assert!(Palette::parse_riff(b"RIFF").is_err());
+    }
+
+    #[test]
+    fn median_cut() {
+        let mut pixels = vec![0xFFFF_0000; 50];
+        pixels.extend([0xFF00_00FF; 50]);
+        pixels.extend([0x0000_FF00; 50]); // Transparentes, se ignoran
+        let palette = Palette::median_cut(&pixels, 4);
+        let mut colors = rgb(&palette);
+        colors.sort();
+        assert_eq!(colors, [[0, 0, 255], [255, 0, 0]]);
+        assert_eq!(
+            palette.nearest(Color::rgb(200, 10, 40)).map(|c| c.r),
+            Some(255)
+        );
+        assert!(Palette::median_cut(&pixels, 0).is_empty());
+    }
+
+    #[test]
+    fn threshold_matrices_are_permutations() {
+        assert_eq!(bayer_matrix(2), [0, 2, 3, 1]);
+        for matrix in [bayer_matrix(8), void_and_cluster(16, 1.5, 7)] {
+            let mut sorted = matrix.clone();
+            sorted.sort_unstable();
+            assert!(
+                sorted
+                    .iter()
+                    .enumerate()
+                    .all(|(i, &rank)| rank as usize == i)
+            );
+        }
+    }
+}
@@ -0,0 +1,526 @@
+use std::any::Any;
+
+/// Efecto de pantalla completa aplicado al buffer después de los plugins.
+///
+/// Lee `src` y escribe el resultado completo en `dst`; ambos miden `width * height`.
+/// Los buffers auxiliares deben guardarse en el efecto para no reservar memoria cada frame.
+pub trait PostEffect: Any {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize);
+
+    // Nombre del efecto para depuración
+    fn name(&self) -> &str {
+        "UnnamedEffect"
+    }
+}
+
+struct PostStage {
+    effect: Box<dyn PostEffect>,
+    enabled: bool,
+}
+
+/// Cadena de efectos que `Galar` aplica a una copia del buffer antes de presentarlo.
+/// El buffer de los plugins no se modifica, así que los efectos no se acumulan entre frames.
+#[derive(Default)]
+pub struct PostProcess {
+    stages: Vec<PostStage>,
+    front: Vec<u32>,
+    back: Vec<u32>,
+}
+
+impl std::fmt::Debug for PostProcess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.stages.iter().map(|stage| (stage.effect.name(), stage.enabled)))
+            .finish()
+    }
+}
+
+impl PostProcess {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with<E: PostEffect>(mut self, effect: E) -> Self {
+        self.push(effect);
+        self
+    }
+
+    /// Añade el efecto al final de la cadena y devuelve su índice
+    pub fn push<E: PostEffect>(&mut self, effect: E) -> usize {
+        self.stages.push(PostStage {
+            effect: Box::new(effect),
+            enabled: true,
+        });
+        self.stages.len() - 1
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<Box<dyn PostEffect>> {
+        (index < self.stages.len()).then(|| self.stages.remove(index).effect)
+    }
+
+    pub fn clear(&mut self) {
+        self.stages.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+
+    /// Índice del primer efecto con ese nombre
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.stages.iter().position(|stage| stage.effect.name() == name)
+    }
+
+    pub fn set_enabled(&mut self, index: usize, enabled: bool) {
+        if let Some(stage) = self.stages.get_mut(index) {
+            stage.enabled = enabled;
+        }
+    }
+
+    pub fn is_enabled(&self, index: usize) -> bool {
+        self.stages.get(index).is_some_and(|stage| stage.enabled)
+    }
+
+    /// Invierte el estado del efecto y devuelve el nuevo
+    pub fn toggle(&mut self, index: usize) -> bool {
+        let enabled = !self.is_enabled(index);
+        self.set_enabled(index, enabled);
+        enabled
+    }
+
+    /// Hay al menos un efecto activo
+    pub fn is_active(&self) -> bool {
+        self.stages.iter().any(|stage| stage.enabled)
+    }
+
+    /// Acceso al efecto con su tipo concreto, para cambiar parámetros en tiempo de ejecución
+    pub fn get<E: PostEffect>(&self, index: usize) -> Option<&E> {
+        let effect: &dyn Any = self.stages.get(index)?.effect.as_ref();
+        effect.downcast_ref()
+    }
+
+    pub fn get_mut<E: PostEffect>(&mut self, index: usize) -> Option<&mut E> {
+        let effect: &mut dyn Any = self.stages.get_mut(index)?.effect.as_mut();
+        effect.downcast_mut()
+    }
+
+    /// Aplica los efectos activos en orden y devuelve el frame final
+    pub fn process<'a>(&'a mut self, frame: &'a [u32], width: usize, height: usize) -> &'a [u32] {
+        let len = width * height;
+        self.front.resize(len, 0);
+        self.back.resize(len, 0);
+
+        let mut source_is_frame = true;
+        for stage in self.stages.iter_mut().filter(|stage| stage.enabled) {
+            if source_is_frame {
+                stage.effect.apply(&frame[..len], &mut self.front, width, height);
+                source_is_frame = false;
+            } else {
+                stage.effect.apply(&self.front, &mut self.back, width, height);
+                std::mem::swap(&mut self.front, &mut self.back);
+            }
+        }
+
+        if source_is_frame { frame } else { &self.front }
+    }
+}
+
+/// Núcleo de convolución con pesos fila por fila. Ancho y alto deben ser impares.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kernel {
+    width: usize,
+    height: usize,
+    weights: Vec<f32>,
+    pub scale: f32,
+    pub bias: f32,
+}
+
+impl Kernel {
+    pub fn new(width: usize, height: usize, weights: Vec<f32>) -> Result<Self, String> {
+        if width.is_multiple_of(2) || height.is_multiple_of(2) {
+            return Err(format!("Kernel size must be odd, got {}x{}", width, height));
+        }
+        if weights.len() != width * height {
+            return Err(format!(
+                "Kernel {}x{} needs {} weights, got {}",
+                width,
+                height,
+                width * height,
+                weights.len()
+            ));
+        }
+        Ok(Self {
+            width,
+            height,
+            weights,
+            scale: 1.0,
+            bias: 0.0,
+        })
+    }
+
+    /// Núcleo 3x3
+    pub fn square3(weights: [f32; 9]) -> Self {
+        Self {
+            width: 3,
+            height: 3,
+            weights: weights.to_vec(),
+            scale: 1.0,
+            bias: 0.0,
+        }
+    }
+
+    /// Divide el resultado por la suma de los pesos (si no es cero)
+    pub fn normalized(mut self) -> Self {
+        let sum: f32 = self.weights.iter().sum();
+        if sum.abs() > f32::EPSILON {
+            self.scale = 1.0 / sum;
+        }
+        self
+    }
+
+    /// Multiplica el resultado, antes de sumar `bias`
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Se suma a cada canal (0.0 .. 255.0); p. ej. 128 para centrar un relieve
+    pub fn with_bias(mut self, bias: f32) -> Self {
+        self.bias = bias;
+        self
+    }
+
+    pub fn identity() -> Self {
+        Self::square3([0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0])
+    }
+
+    pub fn sharpen(amount: f32) -> Self {
+        Self::square3(sharpen_weights(amount))
+    }
+
+    pub fn emboss(strength: f32) -> Self {
+        Self::square3(emboss_weights(strength))
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+}
+
+/// Convolución con un núcleo arbitrario
+#[derive(Debug, Clone)]
+pub struct Convolution {
+    pub kernel: Kernel,
+}
+
+impl Convolution {
+    pub fn new(kernel: Kernel) -> Self {
+        Self { kernel }
+    }
+}
+
+impl PostEffect for Convolution {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        let kernel = &self.kernel;
+        convolve(
+            src,
+            dst,
+            (width, height),
+            &kernel.weights,
+            (kernel.width, kernel.height),
+            kernel.scale,
+            kernel.bias,
+        );
+    }
+    fn name(&self) -> &str {
+        "Convolution"
+    }
+}
+
+/// Desenfoque de caja separable, `radius` en píxeles
+#[derive(Debug, Clone, Default)]
+pub struct BoxBlur {
+    pub radius: usize,
+    weights: Vec<f32>,
+    scratch: Vec<u32>,
+}
+
+impl BoxBlur {
+    pub fn new(radius: usize) -> Self {
+        Self {
+            radius,
+            ..Default::default()
+        }
+    }
+}
+
+impl PostEffect for BoxBlur {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        let taps = 2 * self.radius + 1;
+        if self.weights.len() != taps {
+            self.weights.clear();
+            self.weights.resize(taps, 1.0 / taps as f32);
+        }
+        separable(src, dst, &mut self.scratch, (width, height), &self.weights);
+    }
+    fn name(&self) -> &str {
+        "BoxBlur"
+    }
+}
+
+/// Desenfoque gaussiano separable; el radio del núcleo es `ceil(3 * sigma)`
+#[derive(Debug, Clone, Default)]
+pub struct GaussianBlur {
+    pub sigma: f32,
+    cached_sigma: f32,
+    weights: Vec<f32>,
+    scratch: Vec<u32>,
+}
+
+impl GaussianBlur {
+    pub fn new(sigma: f32) -> Self {
+        Self {
+            sigma,
+            ..Default::default()
+        }
+    }
+}
+
+impl PostEffect for GaussianBlur {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        if self.sigma <= 0.0 {
+            dst.copy_from_slice(src);
+            return;
+        }
+        if self.weights.is_empty() || self.cached_sigma != self.sigma {
+            gaussian_weights(self.sigma, &mut self.weights);
+            self.cached_sigma = self.sigma;
+        }
+        separable(src, dst, &mut self.scratch, (width, height), &self.weights);
+    }
+    fn name(&self) -> &str {
+        "GaussianBlur"
+    }
+}
+
+/// Realce de bordes; `amount` 0.0 no cambia nada
+#[derive(Debug, Clone, Copy)]
+pub struct Sharpen {
+    pub amount: f32,
+}
+
+impl Sharpen {
+    pub fn new(amount: f32) -> Self {
+        Self { amount }
+    }
+}
+
+impl PostEffect for Sharpen {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        let weights = sharpen_weights(self.amount);
+        convolve(src, dst, (width, height), &weights, (3, 3), 1.0, 0.0);
+    }
+    fn name(&self) -> &str {
+        "Sharpen"
+    }
+}
+
+/// Relieve: ilumina los bordes en diagonal
+#[derive(Debug, Clone, Copy)]
+pub struct Emboss {
+    pub strength: f32,
+}
+
+impl Emboss {
+    pub fn new(strength: f32) -> Self {
+        Self { strength }
+    }
+}
+
+impl PostEffect for Emboss {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        let weights = emboss_weights(self.strength);
+        convolve(src, dst, (width, height), &weights, (3, 3), 1.0, 0.0);
+    }
+    fn name(&self) -> &str {
+        "Emboss"
+    }
+}
+
+/// Detección de bordes con Sobel sobre la luminancia; el resultado es en escala de grises
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeDetect {
+    pub strength: f32,
+}
+
+impl EdgeDetect {
+    pub fn new(strength: f32) -> Self {
+        Self { strength }
+    }
+}
+
+impl Default for EdgeDetect {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl PostEffect for EdgeDetect {
+    fn apply(&mut self, src: &[u32], dst: &mut [u32], width: usize, height: usize) {
+        let luma = |x: isize, y: isize| {
+            let x = x.clamp(0, width as isize - 1) as usize;
+            let y = y.clamp(0, height as isize - 1) as usize;
+            luminance(src[y * width + x])
+        };
+
+        for y in 0..height {
+            for x in 0..width {
+                let (xi, yi) = (x as isize, y as isize);
+                let tl = luma(xi - 1, yi - 1);
+                let t = luma(xi, yi - 1);
+                let tr = luma(xi + 1, yi - 1);
+                let l = luma(xi - 1, yi);
+                let r = luma(xi + 1, yi);
+                let bl = luma(xi - 1, yi + 1);
+                let b = luma(xi, yi + 1);
+                let br = luma(xi + 1, yi + 1);
+
+                let gx = (tr + 2.0 * r + br) - (tl + 2.0 * l + bl);
+                let gy = (bl + 2.0 * b + br) - (tl + 2.0 * t + tr);
+                let value = ((gx * gx + gy * gy).sqrt() * self.strength).clamp(0.0, 255.0) as u32;
+
+                let index = y * width + x;
+                dst[index] = (src[index] & 0xFF00_0000) | value << 16 | value << 8 | value;
+            }
+        }
+    }
+    fn name(&self) -> &str {
+        "EdgeDetect"
+    }
+}
+
+fn sharpen_weights(amount: f32) -> [f32; 9] {
+    let a = amount;
+    [0.0, -a, 0.0, -a, 1.0 + 4.0 * a, -a, 0.0, -a, 0.0]
+}
+
+fn emboss_weights(strength: f32) -> [f32; 9] {
+    let s = strength;
+    [-2.0 * s, -s, 0.0, -s, 1.0, s, 0.0, s, 2.0 * s]
+}
+
+pub(crate) fn gaussian_weights(sigma: f32, weights: &mut Vec<f32>) {
+    let radius = (3.0 * sigma).ceil() as isize;
+    weights.clear();
+    weights.extend((-radius..=radius).map(|i| (-((i * i) as f32) / (2.0 * sigma * sigma)).exp()));
+    let sum: f32 = weights.iter().sum();
+    weights.iter_mut().for_each(|weight| *weight /= sum);
+}
+
+/// Separa un píxel AARRGGBB en sus canales de color
+#[inline]
+pub(crate) fn unpack_rgb(pixel: u32) -> [f32; 3] {
+    [
+        ((pixel >> 16) & 0xFF) as f32,
+        ((pixel >> 8) & 0xFF) as f32,
+        (pixel & 0xFF) as f32,
+    ]
+}
+
+/// Reúne los canales (0.0 .. 255.0) con el alfa dado
+#[inline]
+pub(crate) fn pack_rgb(alpha: u32, [r, g, b]: [f32; 3]) -> u32 {
+    let channel = |value: f32| value.round().clamp(0.0, 255.0) as u32;
+    (alpha & 0xFF00_0000) | channel(r) << 16 | channel(g) << 8 | channel(b)
+}
+
+#[inline]
+pub(crate) fn luminance(pixel: u32) -> f32 {
+    let [r, g, b] = unpack_rgb(pixel);
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Convolución 2D con bordes extendidos; el alfa se conserva
+fn convolve(
+    src: &[u32],
+    dst: &mut [u32],
+    (width, height): (usize, usize),
+    weights: &[f32],
+    (kernel_w, kernel_h): (usize, usize),
+    scale: f32,
+    bias: f32,
+) {
+    let (half_w, half_h) = ((kernel_w / 2) as isize, (kernel_h / 2) as isize);
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = [0.0f32; 3];
+            for ky in 0..kernel_h {
+                let sy = (y as isize + ky as isize - half_h).clamp(0, height as isize - 1) as usize;
+                for kx in 0..kernel_w {
+                    let weight = weights[ky * kernel_w + kx];
+                    if weight == 0.0 {
+                        continue;
+                    }
+                    let sx = (x as isize + kx as isize - half_w).clamp(0, width as isize - 1) as usize;
+                    let rgb = unpack_rgb(src[sy * width + sx]);
+                    for (acc, value) in sum.iter_mut().zip(rgb) {
+                        *acc += value * weight;
+                    }
+                }
+            }
+            let index = y * width + x;
+            dst[index] = pack_rgb(src[index], sum.map(|value| value * scale + bias));
+        }
+    }
+}
+
+/// Aplica el mismo núcleo 1D en horizontal y luego en vertical
+pub(crate) fn separable(
+    src: &[u32],
+    dst: &mut [u32],
+    scratch: &mut Vec<u32>,
+    (width, height): (usize, usize),
+    weights: &[f32],
+) {
+    scratch.resize(width * height, 0);
+    let radius = (weights.len() / 2) as isize;
+
+    for y in 0..height {
+        let row = y * width;
+        for x in 0..width {
+            let mut sum = [0.0f32; 3];
+            for (i, weight) in weights.iter().enumerate() {
+                let sx = (x as isize + i as isize - radius).clamp(0, width as isize - 1) as usize;
+                let rgb = unpack_rgb(src[row + sx]);
+                for (acc, value) in sum.iter_mut().zip(rgb) {
+                    *acc += value * weight;
+                }
+            }
+            scratch[row + x] = pack_rgb(src[row + x], sum);
+        }
+    }
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = [0.0f32; 3];
+            for (i, weight) in weights.iter().enumerate() {
+                let sy = (y as isize + i as isize - radius).clamp(0, height as isize - 1) as usize;
+                let rgb = unpack_rgb(scratch[sy * width + x]);
+                for (acc, value) in sum.iter_mut().zip(rgb) {
+                    *acc += value * weight;
+                }
+            }
+            let index = y * width + x;
+            dst[index] = pack_rgb(src[index], sum);
+        }
+    }
+}
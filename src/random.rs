@@ -0,0 +1,141 @@
+use std::cell::RefCell;
+
+use rand::{Rng, RngCore, SeedableRng, rngs::StdRng};
+
+/// Variable de entorno con la semilla, p. ej. `GALAR_SEED=42 cargo run`
+pub const SEED_ENV: &str = "GALAR_SEED";
+/// Argumento de línea de comandos: `--seed 42` o `--seed=42`
+pub const SEED_ARG: &str = "--seed";
+
+/// Generador de números aleatorios con semilla conocida, para poder repetir una obra.
+///
+/// Implementa `RngCore`, así que sirve con todos los métodos de `rand::Rng`
+/// (`random_range`, `random_bool`, ...) y con los helpers `*_with` del crate.
+#[derive(Debug, Clone)]
+pub struct GalarRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl GalarRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Semilla al azar
+    pub fn from_entropy() -> Self {
+        Self::new(rand::random())
+    }
+
+    /// Semilla de `--seed` o `GALAR_SEED` (en ese orden), o al azar si no hay ninguna
+    pub fn from_env() -> Self {
+        Self::seed_from_env().map_or_else(Self::from_entropy, Self::new)
+    }
+
+    /// Lee la semilla de la línea de comandos o del entorno.
+    /// Acepta números o cualquier texto (`--seed atardecer`), que se convierte con un hash estable.
+    pub fn seed_from_env() -> Option<u64> {
+        let mut args = std::env::args().skip(1);
+        let mut from_args = None;
+        while let Some(arg) = args.next() {
+            if arg == SEED_ARG {
+                from_args = args.next();
+            } else if let Some(value) = arg.strip_prefix(SEED_ARG).and_then(|v| v.strip_prefix('='))
+            {
+                from_args = Some(value.to_string());
+            }
+        }
+        from_args
+            .or_else(|| std::env::var(SEED_ENV).ok())
+            .filter(|value| !value.trim().is_empty())
+            .map(|value| parse_seed(&value))
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Reinicia la secuencia con otra semilla
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    /// Generador independiente derivado de este (p. ej. uno por plugin o efecto)
+    pub fn fork(&mut self) -> Self {
+        Self::new(self.rng.next_u64())
+    }
+
+    /// `true` con probabilidad `p` (0.0 .. 1.0)
+    pub fn chance(&mut self, p: f32) -> bool {
+        self.rng.random_bool(p.clamp(0.0, 1.0) as f64)
+    }
+
+    /// Elemento al azar de la lista
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        items.get(self.rng.random_range(0..items.len()))
+    }
+}
+
+impl Default for GalarRng {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+impl RngCore for GalarRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+}
+
+/// Números tal cual; cualquier otro texto con FNV-1a (estable entre ejecuciones y plataformas)
+pub fn parse_seed(text: &str) -> u64 {
+    let text = text.trim();
+    if let Ok(seed) = text.parse() {
+        return seed;
+    }
+    if let Some(seed) = text
+        .strip_prefix("0x")
+        .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+    {
+        return seed;
+    }
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+thread_local! {
+    static THREAD_RNG: RefCell<GalarRng> = RefCell::new(GalarRng::from_entropy());
+}
+
+/// Generador del hilo actual, usado por `Color::random`, `roll_dice` y los efectos.
+/// `Galar` lo siembra a partir de su semilla.
+pub fn with_rng<R>(f: impl FnOnce(&mut GalarRng) -> R) -> R {
+    THREAD_RNG.with(|rng| f(&mut rng.borrow_mut()))
+}
+
+/// Reinicia el generador del hilo actual
+pub fn set_thread_seed(seed: u64) {
+    with_rng(|rng| rng.reseed(seed));
+}
+
+// Semilla del generador del hilo, distinta de la de `ConfigGalar::rng` para que
+// ambas secuencias no repitan los mismos números
+pub(crate) fn thread_seed_for(seed: u64) -> u64 {
+    seed ^ 0x9e37_79b9_7f4a_7c15
+}
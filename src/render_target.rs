@@ -0,0 +1,146 @@
+use super::{
+    camera::Camera2D,
+    canvas::{Canvas, slice_fill_span, slice_get, slice_put},
+    texture::{Material, Texture, TextureHandle},
+};
+
+/// Buffer fuera de pantalla de cualquier tamaño.
+///
+/// Se dibuja en él con `ConfigGalar::draw_to` o pasándolo como `Canvas`,
+/// y luego se usa como textura de un `Material` (minimapas, capas cacheadas,
+/// efectos de realimentación, picture-in-picture).
+#[derive(Debug, Clone)]
+pub struct RenderTarget {
+    buffer: Vec<u32>,
+    width: usize,
+    height: usize,
+    pub camera: Camera2D,
+    opaque: bool,
+    handle: Option<TextureHandle>,
+}
+
+impl RenderTarget {
+    /// Crea un target transparente (alfa 0), con la cámara identidad
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            buffer: vec![0; width * height],
+            width,
+            height,
+            camera: Camera2D::default(),
+            opaque: false,
+            handle: None,
+        }
+    }
+
+    pub fn with_camera(mut self, camera: Camera2D) -> Self {
+        self.camera = camera;
+        self
+    }
+
+    /// Fuerza alfa 255 al convertir a textura. Útil si se dibuja con colores
+    /// hexadecimales sin canal alfa (p. ej. `0xFF0000`), que de otra forma no se verían.
+    pub fn with_opaque(mut self, opaque: bool) -> Self {
+        self.opaque = opaque;
+        self
+    }
+
+    pub fn pixels(&self) -> &[u32] {
+        &self.buffer
+    }
+
+    /// Cambia el tamaño; el contenido anterior se pierde
+    pub fn resize(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+        self.buffer.clear();
+        self.buffer.resize(width * height, 0);
+    }
+
+    /// Copia del contenido actual como textura independiente
+    pub fn to_texture(&self) -> Texture {
+        let data = if self.opaque {
+            self.buffer.iter().map(|pixel| pixel | 0xFF00_0000).collect()
+        } else {
+            self.buffer.clone()
+        };
+        Texture::new(self.width as u32, self.height as u32, data)
+    }
+
+    /// Handle con el contenido actual. Es siempre el mismo handle y se
+    /// refresca al terminar cada `draw_to` o al llamar a `sync_texture`.
+    pub fn texture(&mut self) -> TextureHandle {
+        let texture = self.to_texture();
+        match &self.handle {
+            Some(handle) => {
+                handle.replace(texture);
+                handle.clone()
+            }
+            None => self.handle.insert(TextureHandle::new(texture)).clone(),
+        }
+    }
+
+    /// Material que muestra este target
+    pub fn material(&mut self) -> Material {
+        Material::from_texture(self.texture())
+    }
+
+    /// Copia el buffer al handle compartido, si ya se pidió uno
+    pub fn sync_texture(&mut self) {
+        if let Some(handle) = &self.handle {
+            handle.replace(self.to_texture());
+        }
+    }
+
+    /// Copia los píxeles a `canvas` con la esquina superior izquierda en (x, y),
+    /// en coordenadas de pantalla (sin cámara). Salvo que el target sea opaco, los
+    /// píxeles se componen por su alfa (los de alfa 0 se omiten).
+    pub fn blit<C: Canvas + ?Sized>(&self, canvas: &mut C, x: isize, y: isize) {
+        let (canvas_width, canvas_height) = canvas.size();
+
+        for row in 0..self.height {
+            let dst_y = y + row as isize;
+            if dst_y < 0 || dst_y >= canvas_height as isize {
+                continue;
+            }
+            for col in 0..self.width {
+                let dst_x = x + col as isize;
+                if dst_x < 0 || dst_x >= canvas_width as isize {
+                    continue;
+                }
+                let pixel = self.buffer[row * self.width + col];
+                if self.opaque {
+                    canvas.put_pixel(dst_x as usize, dst_y as usize, pixel);
+                } else {
+                    canvas.blend_pixel(dst_x as usize, dst_y as usize, pixel);
+                }
+            }
+        }
+    }
+
+    // Usado por `ConfigGalar::draw_to` para redirigir el dibujo
+    pub(crate) fn swap_buffer(&mut self, buffer: &mut Vec<u32>) {
+        std::mem::swap(&mut self.buffer, buffer);
+    }
+}
+
+impl Canvas for RenderTarget {
+    fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn get_pixel(&self, x: usize, y: usize) -> Option<u32> {
+        slice_get(&self.buffer, self.width, self.height, x, y)
+    }
+
+    fn put_pixel(&mut self, x: usize, y: usize, color: u32) {
+        slice_put(&mut self.buffer, self.width, self.height, x, y, color);
+    }
+
+    fn fill_span(&mut self, x0: usize, x1: usize, y: usize, color: u32) {
+        slice_fill_span(&mut self.buffer, self.width, self.height, x0, x1, y, color);
+    }
+
+    fn camera(&self) -> Option<&Camera2D> {
+        Some(&self.camera)
+    }
+}
@@ -0,0 +1,195 @@
+use glam::{Affine2, Vec2};
+
+use super::{
+    canvas::Canvas,
+    draws::draw_shape_with_matrix,
+    shape::Shape,
+    transform::Transform,
+    utils::{ConfigGalar, PluginGalar},
+};
+
+/// Nodo de un grafo de escena: Transform local, Shape opcional e hijos.
+///
+/// La matriz del mundo se cachea y solo se recalcula cuando el nodo o
+/// alguno de sus ancestros cambió (dirty flag).
+#[derive(Debug, Clone)]
+pub struct SceneNode {
+    pub name: String,
+    pub shape: Option<Shape>,
+    pub visible: bool,
+    pub layer: usize, // Z-index para orden de dibujo, como en capas
+    transform: Transform,
+    children: Vec<SceneNode>,
+    world: Affine2,
+    dirty: bool,
+}
+
+impl SceneNode {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            shape: None,
+            visible: true,
+            layer: 0,
+            transform: Transform::identity(),
+            children: Vec::new(),
+            world: Affine2::IDENTITY,
+            dirty: true,
+        }
+    }
+
+    pub fn with_shape(mut self, shape: Shape) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.set_transform(transform);
+        self
+    }
+    pub fn with_position(mut self, x: f32, y: f32) -> Self {
+        self.set_position(x, y);
+        self
+    }
+    pub fn with_rotation(mut self, degree: f32) -> Self {
+        self.set_rotation(degree);
+        self
+    }
+    pub fn with_scale(mut self, x: f32, y: f32) -> Self {
+        self.set_scale(x, y);
+        self
+    }
+    pub fn with_layer(mut self, layer: usize) -> Self {
+        self.layer = layer;
+        self
+    }
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+    pub fn with_child(mut self, mut child: SceneNode) -> Self {
+        child.dirty = true;
+        self.children.push(child);
+        self
+    }
+
+    pub fn transform(&self) -> &Transform {
+        &self.transform
+    }
+    /// Acceso mutable al Transform local, marca el nodo como sucio
+    pub fn transform_mut(&mut self) -> &mut Transform {
+        self.dirty = true;
+        &mut self.transform
+    }
+    pub fn set_transform(&mut self, transform: Transform) {
+        *self.transform_mut() = transform;
+    }
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.transform_mut().translation = Vec2::new(x, y);
+    }
+    pub fn set_rotation(&mut self, degree: f32) {
+        self.transform_mut().rotation = degree.to_radians();
+    }
+    pub fn set_scale(&mut self, x: f32, y: f32) {
+        self.transform_mut().scale = Vec2::new(x, y);
+    }
+    /// Suma un ángulo en grados a la rotación local
+    pub fn rotate(&mut self, degree: f32) {
+        self.transform_mut().rotation += degree.to_radians();
+    }
+
+    /// Añade un hijo y devuelve una referencia para seguir construyendo
+    /// El hijo se marca sucio: su matriz cacheada era relativa a otro padre
+    pub fn add_child(&mut self, mut child: SceneNode) -> &mut SceneNode {
+        child.dirty = true;
+        self.children.push(child);
+        self.children.last_mut().unwrap()
+    }
+    /// Quita el primer hijo directo con ese nombre
+    pub fn remove_child(&mut self, name: &str) -> Option<SceneNode> {
+        let index = self.children.iter().position(|child| child.name == name)?;
+        let mut child = self.children.remove(index);
+        child.dirty = true;
+        Some(child)
+    }
+    pub fn children(&self) -> &[SceneNode] {
+        &self.children
+    }
+    pub fn children_mut(&mut self) -> &mut [SceneNode] {
+        &mut self.children
+    }
+
+    /// Busca en profundidad un nodo por nombre (incluye a `self`)
+    pub fn find(&self, name: &str) -> Option<&SceneNode> {
+        if self.name == name {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(name))
+    }
+    pub fn find_mut(&mut self, name: &str) -> Option<&mut SceneNode> {
+        if self.name == name {
+            return Some(self);
+        }
+        self.children.iter_mut().find_map(|child| child.find_mut(name))
+    }
+
+    /// Matriz del mundo cacheada, válida después de `update_world`
+    pub fn world_matrix(&self) -> Affine2 {
+        self.world
+    }
+    /// Posición del origen local en el mundo, válida después de `update_world`
+    pub fn world_position(&self) -> Vec2 {
+        self.world.translation
+    }
+
+    /// Propaga las transformaciones desde este nodo como raíz
+    pub fn update_world(&mut self) {
+        self.propagate(Affine2::IDENTITY, false);
+    }
+
+    fn propagate(&mut self, parent: Affine2, parent_changed: bool) {
+        let changed = self.dirty || parent_changed;
+        if changed {
+            self.world = parent * self.transform.matrix();
+            self.dirty = false;
+        }
+        for child in self.children.iter_mut() {
+            child.propagate(self.world, changed);
+        }
+    }
+
+    /// Dibuja los shapes visibles ordenados por capa.
+    /// Un nodo oculto oculta también a sus hijos.
+    pub fn draw<C: Canvas + ?Sized>(&self, canvas: &mut C) {
+        let mut draw_list = Vec::new();
+        self.collect(&mut draw_list);
+
+        // Orden estable: a igual capa se respeta el orden del árbol
+        draw_list.sort_by_key(|(layer, _, _)| *layer);
+
+        for (_, shape, model) in draw_list {
+            draw_shape_with_matrix(canvas, shape, model);
+        }
+    }
+
+    fn collect<'a>(&'a self, draw_list: &mut Vec<(usize, &'a Shape, Affine2)>) {
+        if !self.visible {
+            return;
+        }
+        if let Some(shape) = &self.shape {
+            draw_list.push((self.layer, shape, self.world * shape.model_matrix()));
+        }
+        for child in self.children.iter() {
+            child.collect(draw_list);
+        }
+    }
+}
+
+impl PluginGalar for SceneNode {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        self.update_world();
+        self.draw(config);
+    }
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,797 @@
+use glam::{Affine2, Vec2};
+
+use super::{
+    canvas::Canvas,
+    colores::Color,
+    linear_color::LinearColor,
+    transform::Transform,
+    utils::{ConfigGalar, PluginGalar},
+};
+
+/// Campo de distancia con signo en 2D: negativo dentro, positivo fuera y 0 en el borde.
+///
+/// Todas las formas y operaciones de este módulo son 1-Lipschitz (la distancia no
+/// crece más rápido que el desplazamiento), lo que permite el recorte por bloques de
+/// `draw_sdf` y el `raycast` por sphere tracing.
+pub trait Sdf {
+    fn distance(&self, p: Vec2) -> f32;
+
+    /// Dentro de la forma o sobre el borde
+    fn contains(&self, p: Vec2) -> bool {
+        self.distance(p) <= 0.0
+    }
+
+    /// Dirección de máximo crecimiento de la distancia (normal hacia fuera en el borde)
+    fn normal(&self, p: Vec2) -> Vec2 {
+        const EPSILON: f32 = 1e-3;
+        let dx = self.distance(p + Vec2::X * EPSILON) - self.distance(p - Vec2::X * EPSILON);
+        let dy = self.distance(p + Vec2::Y * EPSILON) - self.distance(p - Vec2::Y * EPSILON);
+        Vec2::new(dx, dy).normalize_or_zero()
+    }
+
+    /// Punto más cercano del borde
+    fn closest_point(&self, p: Vec2) -> Vec2 {
+        p - self.normal(p) * self.distance(p)
+    }
+
+    /// Un círculo toca o atraviesa la forma
+    fn overlaps_circle(&self, center: Vec2, radius: f32) -> bool {
+        self.distance(center) <= radius
+    }
+
+    /// Desplazamiento mínimo para sacar el círculo de la forma, `None` si no la toca
+    fn resolve_circle(&self, center: Vec2, radius: f32) -> Option<Vec2> {
+        let distance = self.distance(center);
+        (distance < radius).then(|| self.normal(center) * (radius - distance))
+    }
+
+    /// Distancia recorrida por el rayo hasta chocar con el borde (sphere tracing)
+    fn raycast(&self, origin: Vec2, direction: Vec2, max_distance: f32) -> Option<f32> {
+        const HIT: f32 = 1e-3;
+        let direction = direction.normalize_or_zero();
+        if direction == Vec2::ZERO {
+            return None;
+        }
+        let mut traveled = 0.0;
+        for _ in 0..256 {
+            let distance = self.distance(origin + direction * traveled);
+            if distance.abs() < HIT {
+                return Some(traveled);
+            }
+            traveled += distance.abs();
+            if traveled > max_distance {
+                break;
+            }
+        }
+        None
+    }
+
+    fn union<B: Sdf>(self, other: B) -> SdfCombine<Self, B>
+    where
+        Self: Sized,
+    {
+        SdfCombine::new(self, other, SdfOperation::Union, 0.0)
+    }
+
+    fn intersection<B: Sdf>(self, other: B) -> SdfCombine<Self, B>
+    where
+        Self: Sized,
+    {
+        SdfCombine::new(self, other, SdfOperation::Intersection, 0.0)
+    }
+
+    /// `self` menos `other`
+    fn subtraction<B: Sdf>(self, other: B) -> SdfCombine<Self, B>
+    where
+        Self: Sized,
+    {
+        SdfCombine::new(self, other, SdfOperation::Subtraction, 0.0)
+    }
+
+    /// Unión con una transición suave de ancho `k`
+    fn smooth_union<B: Sdf>(self, other: B, k: f32) -> SdfCombine<Self, B>
+    where
+        Self: Sized,
+    {
+        SdfCombine::new(self, other, SdfOperation::Union, k)
+    }
+
+    fn smooth_intersection<B: Sdf>(self, other: B, k: f32) -> SdfCombine<Self, B>
+    where
+        Self: Sized,
+    {
+        SdfCombine::new(self, other, SdfOperation::Intersection, k)
+    }
+
+    fn smooth_subtraction<B: Sdf>(self, other: B, k: f32) -> SdfCombine<Self, B>
+    where
+        Self: Sized,
+    {
+        SdfCombine::new(self, other, SdfOperation::Subtraction, k)
+    }
+
+    /// Engorda la forma `radius` y redondea sus esquinas
+    fn rounded(self, radius: f32) -> SdfRound<Self>
+    where
+        Self: Sized,
+    {
+        SdfRound {
+            shape: self,
+            radius,
+        }
+    }
+
+    /// Solo una cáscara de `thickness` alrededor del borde
+    fn onion(self, thickness: f32) -> SdfOnion<Self>
+    where
+        Self: Sized,
+    {
+        SdfOnion {
+            shape: self,
+            thickness,
+        }
+    }
+
+    /// Repite la forma cada `period` (un eje con periodo 0 no se repite)
+    fn repeat(self, period: Vec2) -> SdfRepeat<Self>
+    where
+        Self: Sized,
+    {
+        SdfRepeat::new(self, period, None)
+    }
+
+    /// Repite la forma `count` veces a cada lado del origen
+    fn repeat_limited(self, period: Vec2, count: Vec2) -> SdfRepeat<Self>
+    where
+        Self: Sized,
+    {
+        SdfRepeat::new(self, period, Some(count))
+    }
+
+    fn transformed(self, transform: Transform) -> SdfTransform<Self>
+    where
+        Self: Sized,
+    {
+        SdfTransform::new(self, transform)
+    }
+
+    fn translated(self, offset: Vec2) -> SdfTransform<Self>
+    where
+        Self: Sized,
+    {
+        self.transformed(Transform::from_translation(offset.x, offset.y))
+    }
+
+    /// Rotación en radianes alrededor del origen
+    fn rotated(self, angle: f32) -> SdfTransform<Self>
+    where
+        Self: Sized,
+    {
+        self.transformed(Transform::new(0.0, 0.0, angle, 1.0, 1.0))
+    }
+
+    /// Escala uniforme respecto al origen
+    fn scaled(self, factor: f32) -> SdfTransform<Self>
+    where
+        Self: Sized,
+    {
+        self.transformed(Transform::new(0.0, 0.0, 0.0, factor, factor))
+    }
+}
+
+impl<S: Sdf + ?Sized> Sdf for &S {
+    fn distance(&self, p: Vec2) -> f32 {
+        (**self).distance(p)
+    }
+}
+
+impl<S: Sdf + ?Sized> Sdf for Box<S> {
+    fn distance(&self, p: Vec2) -> f32 {
+        (**self).distance(p)
+    }
+}
+
+/// Adapta cualquier función `Fn(Vec2) -> f32` como `Sdf`
+#[derive(Debug, Clone, Copy)]
+pub struct SdfFn<F>(pub F);
+
+impl<F: Fn(Vec2) -> f32> Sdf for SdfFn<F> {
+    fn distance(&self, p: Vec2) -> f32 {
+        (self.0)(p)
+    }
+}
+
+/// Las fórmulas de las primitivas siguen a Inigo Quilez (iquilezles.org).
+/// Los ángulos se miden en pantalla: y crece hacia abajo y "arriba" es -y.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfCircle {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+impl SdfCircle {
+    pub fn new(center: Vec2, radius: f32) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl Sdf for SdfCircle {
+    fn distance(&self, p: Vec2) -> f32 {
+        (p - self.center).length() - self.radius
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfBox {
+    pub center: Vec2,
+    pub half_size: Vec2,
+}
+
+impl SdfBox {
+    pub fn new(center: Vec2, half_size: Vec2) -> Self {
+        Self { center, half_size }
+    }
+
+    /// Desde la esquina superior izquierda y el tamaño completo
+    pub fn from_rect(x: f32, y: f32, width: f32, height: f32) -> Self {
+        let half_size = Vec2::new(width, height) * 0.5;
+        Self::new(Vec2::new(x, y) + half_size, half_size)
+    }
+}
+
+impl Sdf for SdfBox {
+    fn distance(&self, p: Vec2) -> f32 {
+        let d = (p - self.center).abs() - self.half_size;
+        d.max(Vec2::ZERO).length() + d.x.max(d.y).min(0.0)
+    }
+}
+
+/// Caja con un radio por esquina: superior izquierda, superior derecha,
+/// inferior derecha e inferior izquierda
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfRoundedBox {
+    pub center: Vec2,
+    pub half_size: Vec2,
+    pub radii: [f32; 4],
+}
+
+impl SdfRoundedBox {
+    pub fn new(center: Vec2, half_size: Vec2, radius: f32) -> Self {
+        Self {
+            center,
+            half_size,
+            radii: [radius; 4],
+        }
+    }
+
+    pub fn with_radii(mut self, radii: [f32; 4]) -> Self {
+        self.radii = radii;
+        self
+    }
+}
+
+impl Sdf for SdfRoundedBox {
+    fn distance(&self, p: Vec2) -> f32 {
+        let p = p - self.center;
+        let [top_left, top_right, bottom_right, bottom_left] = self.radii;
+        let radius = match (p.x > 0.0, p.y > 0.0) {
+            (false, false) => top_left,
+            (true, false) => top_right,
+            (true, true) => bottom_right,
+            (false, true) => bottom_left,
+        };
+        let radius = radius.clamp(0.0, self.half_size.min_element());
+        let q = p.abs() - self.half_size + radius;
+        q.x.max(q.y).min(0.0) + q.max(Vec2::ZERO).length() - radius
+    }
+}
+
+/// Segmento de `a` a `b`; con `radius` > 0 es una cápsula
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfSegment {
+    pub a: Vec2,
+    pub b: Vec2,
+    pub radius: f32,
+}
+
+impl SdfSegment {
+    pub fn new(a: Vec2, b: Vec2, radius: f32) -> Self {
+        Self { a, b, radius }
+    }
+}
+
+impl Sdf for SdfSegment {
+    fn distance(&self, p: Vec2) -> f32 {
+        let pa = p - self.a;
+        let ba = self.b - self.a;
+        let h = (pa.dot(ba) / ba.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0);
+        (pa - ba * h).length() - self.radius
+    }
+}
+
+/// Polígono cerrado; sirve cualquier orden de vértices, incluso no convexo
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SdfPolygon {
+    pub points: Vec<Vec2>,
+}
+
+impl SdfPolygon {
+    pub fn new(points: Vec<Vec2>) -> Self {
+        Self { points }
+    }
+
+    /// Polígono regular de `sides` lados con un vértice hacia arriba
+    pub fn regular(center: Vec2, radius: f32, sides: usize) -> Self {
+        let sides = sides.max(3);
+        let points = (0..sides)
+            .map(|i| {
+                let angle = i as f32 / sides as f32 * std::f32::consts::TAU;
+                center + Vec2::new(angle.sin(), -angle.cos()) * radius
+            })
+            .collect();
+        Self { points }
+    }
+}
+
+impl Sdf for SdfPolygon {
+    fn distance(&self, p: Vec2) -> f32 {
+        let Some(&first) = self.points.first() else {
+            return f32::INFINITY;
+        };
+        let mut distance = (p - first).length_squared();
+        let mut sign = 1.0;
+
+        let mut j = self.points.len() - 1;
+        for (i, &vi) in self.points.iter().enumerate() {
+            let vj = self.points[j];
+            let e = vj - vi;
+            let w = p - vi;
+            let b = w - e * (w.dot(e) / e.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0);
+            distance = distance.min(b.length_squared());
+
+            // Regla par-impar para saber si está dentro
+            let crossing = [p.y >= vi.y, p.y < vj.y, e.x * w.y > e.y * w.x];
+            if crossing.iter().all(|&c| c) || crossing.iter().all(|&c| !c) {
+                sign = -sign;
+            }
+            j = i;
+        }
+        sign * distance.sqrt()
+    }
+}
+
+/// Arco de circunferencia con grosor.
+/// `aperture` es medio ángulo de apertura y `rotation` gira el arco (0 = centrado arriba).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfArc {
+    pub center: Vec2,
+    pub radius: f32,
+    pub aperture: f32,
+    pub thickness: f32,
+    pub rotation: f32,
+}
+
+impl SdfArc {
+    pub fn new(center: Vec2, radius: f32, aperture: f32, thickness: f32) -> Self {
+        Self {
+            center,
+            radius,
+            aperture,
+            thickness,
+            rotation: 0.0,
+        }
+    }
+
+    pub fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+}
+
+impl Sdf for SdfArc {
+    fn distance(&self, p: Vec2) -> f32 {
+        let p = Vec2::from_angle(-self.rotation).rotate(p - self.center);
+        let p = Vec2::new(p.x.abs(), -p.y);
+        let (sin, cos) = self.aperture.clamp(0.0, std::f32::consts::PI).sin_cos();
+        let tip = Vec2::new(sin, cos);
+        let distance = if cos * p.x > sin * p.y {
+            (p - tip * self.radius).length()
+        } else {
+            (p.length() - self.radius).abs()
+        };
+        distance - self.thickness * 0.5
+    }
+}
+
+/// Estrella de `points` puntas con una hacia arriba.
+/// `m` va de 2.0 (puntas finas) a `points` (polígono regular).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfStar {
+    pub center: Vec2,
+    pub radius: f32,
+    pub points: u32,
+    pub m: f32,
+}
+
+impl SdfStar {
+    pub fn new(center: Vec2, radius: f32, points: u32) -> Self {
+        let points = points.max(3);
+        Self {
+            center,
+            radius,
+            points,
+            m: 2.5_f32.min(points as f32),
+        }
+    }
+
+    pub fn with_m(mut self, m: f32) -> Self {
+        self.m = m;
+        self
+    }
+}
+
+impl Sdf for SdfStar {
+    fn distance(&self, p: Vec2) -> f32 {
+        use std::f32::consts::PI;
+
+        let p = p - self.center;
+        let p = Vec2::new(p.x, -p.y);
+        let n = self.points.max(3) as f32;
+        let m = self.m.clamp(2.0, n);
+
+        let an = PI / n;
+        let en = PI / m;
+        let acs = Vec2::new(an.cos(), an.sin());
+        let ecs = Vec2::new(en.cos(), en.sin());
+
+        // Se pliega el plano al sector de una punta
+        let bn = p.x.atan2(p.y).rem_euclid(2.0 * an) - an;
+        let mut q = p.length() * Vec2::new(bn.cos(), bn.sin().abs());
+        q -= self.radius * acs;
+        q += ecs * (-q.dot(ecs)).clamp(0.0, self.radius * acs.y / ecs.y);
+        q.length() * q.x.signum()
+    }
+}
+
+/// Operación booleana de `SdfCombine`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SdfOperation {
+    #[default]
+    Union,
+    Intersection,
+    /// `a` menos `b`
+    Subtraction,
+}
+
+/// Mínimo suave polinómico: igual a `min` cuando `a` y `b` están a más de `k`
+pub fn smooth_min(a: f32, b: f32, k: f32) -> f32 {
+    if k <= 0.0 {
+        return a.min(b);
+    }
+    let h = (k - (a - b).abs()).max(0.0) / k;
+    a.min(b) - h * h * k * 0.25
+}
+
+/// Combina dos formas; con `smoothness` > 0 la unión se funde suavemente
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfCombine<A, B> {
+    pub a: A,
+    pub b: B,
+    pub operation: SdfOperation,
+    pub smoothness: f32,
+}
+
+impl<A: Sdf, B: Sdf> SdfCombine<A, B> {
+    pub fn new(a: A, b: B, operation: SdfOperation, smoothness: f32) -> Self {
+        Self {
+            a,
+            b,
+            operation,
+            smoothness,
+        }
+    }
+}
+
+impl<A: Sdf, B: Sdf> Sdf for SdfCombine<A, B> {
+    fn distance(&self, p: Vec2) -> f32 {
+        let (a, b) = (self.a.distance(p), self.b.distance(p));
+        let k = self.smoothness;
+        match self.operation {
+            SdfOperation::Union => smooth_min(a, b, k),
+            SdfOperation::Intersection => -smooth_min(-a, -b, k),
+            SdfOperation::Subtraction => -smooth_min(-a, b, k),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfRound<S> {
+    pub shape: S,
+    pub radius: f32,
+}
+
+impl<S: Sdf> Sdf for SdfRound<S> {
+    fn distance(&self, p: Vec2) -> f32 {
+        self.shape.distance(p) - self.radius
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfOnion<S> {
+    pub shape: S,
+    pub thickness: f32,
+}
+
+impl<S: Sdf> Sdf for SdfOnion<S> {
+    fn distance(&self, p: Vec2) -> f32 {
+        self.shape.distance(p).abs() - self.thickness * 0.5
+    }
+}
+
+/// Repetición del dominio: la forma debe caber en una celda de `period`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SdfRepeat<S> {
+    pub shape: S,
+    pub period: Vec2,
+    /// Copias a cada lado del origen; `None` repite sin límite
+    pub count: Option<Vec2>,
+}
+
+impl<S: Sdf> SdfRepeat<S> {
+    pub fn new(shape: S, period: Vec2, count: Option<Vec2>) -> Self {
+        Self {
+            shape,
+            period,
+            count,
+        }
+    }
+}
+
+impl<S: Sdf> Sdf for SdfRepeat<S> {
+    fn distance(&self, p: Vec2) -> f32 {
+        let axis = |value: f32, period: f32, count: Option<f32>| {
+            if period <= 0.0 {
+                return value;
+            }
+            let mut cell = (value / period).round();
+            if let Some(count) = count {
+                cell = cell.clamp(-count, count);
+            }
+            value - period * cell
+        };
+        let q = Vec2::new(
+            axis(p.x, self.period.x, self.count.map(|c| c.x)),
+            axis(p.y, self.period.y, self.count.map(|c| c.y)),
+        );
+        self.shape.distance(q)
+    }
+}
+
+/// Forma con un `Transform`. La distancia es exacta con escala uniforme;
+/// con escala no uniforme o sesgo es una cota inferior.
+#[derive(Debug, Clone, Copy)]
+pub struct SdfTransform<S> {
+    pub shape: S,
+    transform: Transform,
+    inverse: Affine2,
+    factor: f32,
+}
+
+impl<S: Sdf> SdfTransform<S> {
+    pub fn new(shape: S, transform: Transform) -> Self {
+        let mut sdf = Self {
+            shape,
+            transform,
+            inverse: Affine2::IDENTITY,
+            factor: 1.0,
+        };
+        sdf.set_transform(transform);
+        sdf
+    }
+
+    pub fn transform(&self) -> &Transform {
+        &self.transform
+    }
+
+    /// La inversa se calcula una vez aquí y no en cada consulta
+    pub fn set_transform(&mut self, transform: Transform) {
+        let matrix = transform.matrix();
+        self.transform = transform;
+        self.inverse = matrix.inverse();
+        // Menor valor singular de la parte lineal: cuánto encoge como mucho
+        let m = matrix.matrix2;
+        let (a, b, c, d) = (m.x_axis.x, m.y_axis.x, m.x_axis.y, m.y_axis.y);
+        let sum = a * a + b * b + c * c + d * d;
+        let det = (a * d - b * c).abs();
+        let root = (sum * sum - 4.0 * det * det).max(0.0).sqrt();
+        self.factor = ((sum - root) * 0.5).max(0.0).sqrt();
+    }
+}
+
+impl<S: Sdf> Sdf for SdfTransform<S> {
+    fn distance(&self, p: Vec2) -> f32 {
+        self.shape.distance(self.inverse.transform_point2(p)) * self.factor
+    }
+}
+
+/// Relleno y contorno con los que se dibuja una SDF
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SdfStyle {
+    pub fill: Option<Color>,
+    pub outline: Option<Color>,
+    /// Ancho del contorno en unidades del mundo, centrado en el borde
+    pub outline_width: f32,
+}
+
+impl SdfStyle {
+    pub fn fill(color: Color) -> Self {
+        Self {
+            fill: Some(color),
+            ..Default::default()
+        }
+    }
+
+    pub fn outline(color: Color, width: f32) -> Self {
+        Self::default().with_outline(color, width)
+    }
+
+    pub fn with_fill(mut self, color: Color) -> Self {
+        self.fill = Some(color);
+        self
+    }
+
+    pub fn with_outline(mut self, color: Color, width: f32) -> Self {
+        self.outline = Some(color);
+        self.outline_width = width;
+        self
+    }
+}
+
+// Lado de los bloques que se descartan o rellenan sin evaluar cada píxel
+const SDF_TILE: usize = 8;
+
+/// Dibuja la SDF con bordes antialiasados (cobertura mezclada en luz lineal).
+/// Respeta la cámara del canvas: la forma está en coordenadas del mundo.
+pub fn draw_sdf<C: Canvas + ?Sized, S: Sdf + ?Sized>(canvas: &mut C, shape: &S, style: &SdfStyle) {
+    let (width, height) = canvas.size();
+    let (to_world, pixel) = match canvas.camera().filter(|camera| !camera.is_identity()) {
+        Some(camera) => (
+            camera.inverse_view_matrix(),
+            1.0 / camera.zoom.abs().max(1e-6),
+        ),
+        None => (Affine2::IDENTITY, 1.0),
+    };
+
+    let fill = style
+        .fill
+        .map(|color| LinearColor::from(color).premultiply());
+    let outline = style
+        .outline
+        .map(|color| LinearColor::from(color).premultiply());
+    let half_outline = if outline.is_some() {
+        style.outline_width.max(0.0) * 0.5
+    } else {
+        0.0
+    };
+    if fill.is_none() && outline.is_none() {
+        return;
+    }
+
+    // Color premultiplicado del píxel a distancia `d` del borde
+    let shade = |d: f32| {
+        let mut color = LinearColor::TRANSPARENT;
+        if let Some(fill) = fill {
+            color = fill * (0.5 - d / pixel).clamp(0.0, 1.0);
+        }
+        if let Some(outline) = outline {
+            let coverage = (0.5 - (d.abs() - half_outline) / pixel).clamp(0.0, 1.0);
+            color = (outline * coverage).over(color);
+        }
+        color
+    };
+
+    let reach = half_outline + pixel;
+    let tile_radius = SDF_TILE as f32 * std::f32::consts::FRAC_1_SQRT_2 * pixel;
+
+    for tile_y in (0..height).step_by(SDF_TILE) {
+        for tile_x in (0..width).step_by(SDF_TILE) {
+            let (end_x, end_y) = (
+                (tile_x + SDF_TILE).min(width),
+                (tile_y + SDF_TILE).min(height),
+            );
+            let center = Vec2::new((tile_x + end_x - 1) as f32, (tile_y + end_y - 1) as f32) * 0.5;
+            let distance = shape.distance(to_world.transform_point2(center));
+
+            // Bloque entero fuera del alcance de la forma
+            if distance > tile_radius + reach {
+                continue;
+            }
+
+            // Bloque entero dentro, lejos del contorno: relleno sin evaluar cada píxel
+            if distance < -(tile_radius + reach) {
+                if let Some(fill) = fill {
+                    let color = fill.unpremultiply().to_hex();
+                    for y in tile_y..end_y {
+                        for x in tile_x..end_x {
+                            canvas.blend_pixel(x, y, color);
+                        }
+                    }
+                }
+                continue;
+            }
+
+            for y in tile_y..end_y {
+                for x in tile_x..end_x {
+                    let world = to_world.transform_point2(Vec2::new(x as f32, y as f32));
+                    let color = shade(shape.distance(world));
+                    if color.a > 0.0 {
+                        canvas.blend_pixel(x, y, color.unpremultiply().to_hex());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Plugin que dibuja una SDF en cada frame
+#[derive(Debug, Clone)]
+pub struct SdfPlugin<S> {
+    pub shape: S,
+    pub style: SdfStyle,
+}
+
+impl<S: Sdf> SdfPlugin<S> {
+    pub fn new(shape: S, style: SdfStyle) -> Self {
+        Self { shape, style }
+    }
+}
+
+impl<S: Sdf + 'static> PluginGalar for SdfPlugin<S> {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        draw_sdf(config, &self.shape, &self.style);
+    }
+    fn name(&self) -> &str {
+        "SDF"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::canvas::BufferCanvas;
+
+    // Caja de (4, 4) a (12, 12): los centros de píxel con x = 12 caen justo en el borde
+    fn draw_box(background: u32) -> Vec<u32> {
+        let mut pixels = vec![background; 16 * 16];
+        let mut canvas = BufferCanvas::new(&mut pixels, 16, 16);
+        let shape = SdfBox::from_rect(4.0, 4.0, 8.0, 8.0);
+        draw_sdf(
+            &mut canvas,
+            &shape,
+            &SdfStyle::fill(Color::rgb(255, 255, 255)),
+        );
+        pixels
+    }
+
+    #[test]
+    fn edges_are_antialiased() {
+        let pixels = draw_box(0xFF00_0000);
+        assert_eq!(pixels[8 * 16 + 8], 0xFFFF_FFFF);
+        assert_eq!(pixels[8 * 16 + 14], 0xFF00_0000);
+
+        let edge = pixels[8 * 16 + 12];
+        assert_eq!(edge >> 24, 0xFF);
+        let channel = edge & 0xFF;
+        assert!(channel > 0 && channel < 0xFF, "{edge:#010x}");
+    }
+
+    #[test]
+    fn coverage_goes_to_alpha_on_transparent_targets() {
+        let pixels = draw_box(0);
+        let edge = pixels[8 * 16 + 12];
+        let alpha = edge >> 24;
+        assert!(alpha > 0 && alpha < 0xFF, "{edge:#010x}");
+        assert_eq!(edge & 0xFF_FFFF, 0xFF_FFFF);
+        assert_eq!(pixels[8 * 16 + 14], 0);
+    }
+}
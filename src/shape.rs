@@ -1,6 +1,6 @@
-use glam::Vec2;
+use glam::{Affine2, Vec2};
 
-use super::{draws::draw_shape, texture::Material, transform::Transform, utils::{ConfigGalar, PluginGalar}};
+use super::{draws::draw_shape, paint::Paint, texture::Material, transform::Transform, utils::{ConfigGalar, PluginGalar}};
 use super::colores::Color;
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -73,9 +73,16 @@ impl Shape {
         self.material = Material {
             base_color: Some(color),
             texture: None,
+            tint: None,
+            paint: None,
         };
         self
     }
+    /// Degradado en el espacio indicado por el `Paint`
+    pub fn with_paint(mut self, paint: Paint) -> Self {
+        self.material = Material::from_paint(paint);
+        self
+    }
     pub fn with_orgien(mut self, origen: bool) -> Self {
         self.origen = origen;
         self
@@ -103,8 +110,13 @@ impl Shape {
         self.material = Material {
             base_color: Some(color),
             texture: None,
+            tint: None,
+            paint: None,
         };
     }
+    pub fn set_paint(&mut self, paint: Paint) {
+        self.material = Material::from_paint(paint);
+    }
     pub fn set_origen(&mut self, origen: bool){
         self.origen = origen;
     }
@@ -116,6 +128,22 @@ impl Shape {
 
         (max_x - min_x, max_y - min_y)
     }
+    /// Matriz de modelo del shape; con `origen` el pivot es el centro del shape
+    pub fn model_matrix(&self) -> Affine2 {
+        if self.origen {
+            let (center_x, center_y) = self.get_center();
+            self.transform.centered(center_x, center_y).matrix()
+        } else {
+            self.transform.matrix()
+        }
+    }
+    pub fn with_pivot(mut self, x: f32, y: f32) -> Self {
+        self.transform.pivot = Vec2::new(x, y);
+        self
+    }
+    pub fn set_pivot(&mut self, x: f32, y: f32) {
+        self.transform.pivot = Vec2::new(x, y);
+    }
     pub fn get_center(&self) -> (f32, f32) {
         let (w, h) = self.get_dimensions();
         let min_x = self.vertices.iter().map(|v| v.x).fold(f32::INFINITY, f32::min);
@@ -154,7 +182,7 @@ pub enum GeometryShape {
 }
 
 impl GeometryShape {
-    fn new_shape(&self) -> Shape {
+    pub fn new_shape(&self) -> Shape {
         match self {
             &GeometryShape::Triangle { x, y, size, color } => Self::shape_triangle(x, y, size, color),
             &GeometryShape::Square { x, y, size, color } => Self::shape_square(x, y, size, color),
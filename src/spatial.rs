@@ -0,0 +1,1170 @@
+use std::collections::{BinaryHeap, HashMap};
+
+use glam::{I64Vec2, IVec2, Vec2};
+
+use super::auxiliar::Float;
+
+/// Identificador estable de un elemento dentro de un índice espacial
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpatialId(usize);
+
+impl SpatialId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Índice de puntos 2D con un valor asociado: partículas, entidades, semillas...
+///
+/// Las consultas devuelven ids; el valor y la posición se leen con `get`.
+pub trait SpatialIndex<T> {
+    fn insert(&mut self, position: Vec2, value: T) -> SpatialId;
+
+    fn remove(&mut self, id: SpatialId) -> Option<(Vec2, T)>;
+
+    /// Mueve el elemento conservando su id; `false` si no existe
+    fn set_position(&mut self, id: SpatialId, position: Vec2) -> bool;
+
+    fn get(&self, id: SpatialId) -> Option<(Vec2, &T)>;
+
+    fn get_mut(&mut self, id: SpatialId) -> Option<&mut T>;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn clear(&mut self);
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (SpatialId, Vec2, &'a T)>
+    where
+        T: 'a;
+
+    /// Los `k` elementos más cercanos, del más próximo al más lejano
+    fn nearest_k(&self, point: Vec2, k: usize) -> Vec<SpatialId>;
+
+    fn nearest(&self, point: Vec2) -> Option<SpatialId> {
+        self.nearest_k(point, 1).first().copied()
+    }
+
+    /// Elementos a una distancia menor o igual que `radius`, sin orden
+    fn within_radius(&self, center: Vec2, radius: f32) -> Vec<SpatialId>;
+
+    /// Elementos dentro del rectángulo `min..=max`, sin orden
+    fn within_rect(&self, min: Vec2, max: Vec2) -> Vec<SpatialId>;
+}
+
+// Almacén común: posición y valor por id, reutilizando los huecos
+#[derive(Debug, Clone)]
+struct Slots<T> {
+    items: Vec<Option<(Vec2, T)>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+impl<T> Default for Slots<T> {
+    fn default() -> Self {
+        Self {
+            items: vec![],
+            free: vec![],
+            len: 0,
+        }
+    }
+}
+
+impl<T> Slots<T> {
+    fn insert(&mut self, position: Vec2, value: T) -> usize {
+        self.len += 1;
+        match self.free.pop() {
+            Some(index) => {
+                self.items[index] = Some((position, value));
+                index
+            }
+            None => {
+                self.items.push(Some((position, value)));
+                self.items.len() - 1
+            }
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> Option<(Vec2, T)> {
+        let item = self.items.get_mut(index)?.take()?;
+        self.free.push(index);
+        self.len -= 1;
+        Some(item)
+    }
+
+    fn position(&self, index: usize) -> Option<Vec2> {
+        self.items
+            .get(index)?
+            .as_ref()
+            .map(|(position, _)| *position)
+    }
+
+    fn get(&self, index: usize) -> Option<(Vec2, &T)> {
+        self.items
+            .get(index)?
+            .as_ref()
+            .map(|(position, value)| (*position, value))
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.items.get_mut(index)?.as_mut().map(|(_, value)| value)
+    }
+
+    /// Devuelve la posición anterior
+    fn set_position(&mut self, index: usize, position: Vec2) -> Option<Vec2> {
+        let (current, _) = self.items.get_mut(index)?.as_mut()?;
+        Some(std::mem::replace(current, position))
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (SpatialId, Vec2, &T)> {
+        self.items.iter().enumerate().filter_map(|(index, item)| {
+            item.as_ref()
+                .map(|(position, value)| (SpatialId(index), *position, value))
+        })
+    }
+
+    fn clear(&mut self) {
+        self.items.clear();
+        self.free.clear();
+        self.len = 0;
+    }
+}
+
+// Los k mejores candidatos en un montículo de máximos por distancia al cuadrado
+struct NearestK {
+    k: usize,
+    heap: BinaryHeap<(Float, usize)>,
+}
+
+impl NearestK {
+    fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    fn offer(&mut self, distance_squared: f32, index: usize) {
+        if self.k == 0 {
+            return;
+        }
+        if self.heap.len() < self.k {
+            self.heap.push((Float(distance_squared), index));
+        } else if distance_squared < self.bound() {
+            self.heap.pop();
+            self.heap.push((Float(distance_squared), index));
+        }
+    }
+
+    /// Distancia al cuadrado que debe mejorar un candidato para entrar
+    fn bound(&self) -> f32 {
+        if self.heap.len() < self.k {
+            f32::INFINITY
+        } else {
+            self.heap
+                .peek()
+                .map_or(f32::INFINITY, |(distance, _)| distance.0)
+        }
+    }
+
+    fn into_ids(self) -> Vec<SpatialId> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|(_, index)| SpatialId(index))
+            .collect()
+    }
+}
+
+fn in_rect(point: Vec2, min: Vec2, max: Vec2) -> bool {
+    point.cmpge(min).all() && point.cmple(max).all()
+}
+
+// Distancia al cuadrado de un punto a una caja (0 si está dentro)
+fn box_distance_squared(point: Vec2, min: Vec2, max: Vec2) -> f32 {
+    (min - point)
+        .max(point - max)
+        .max(Vec2::ZERO)
+        .length_squared()
+}
+
+/// Cuadrícula uniforme dispersa: cada celda de `cell_size` guarda sus elementos.
+/// Ideal para muchos objetos de tamaño parecido que se mueven en cada frame.
+#[derive(Debug, Clone)]
+pub struct HashGrid<T> {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<usize>>,
+    slots: Slots<T>,
+    // Celdas ocupadas alguna vez, para acotar los recorridos
+    min_cell: IVec2,
+    max_cell: IVec2,
+}
+
+impl<T> HashGrid<T> {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size: cell_size.max(f32::EPSILON),
+            cells: HashMap::new(),
+            slots: Slots::default(),
+            min_cell: IVec2::MAX,
+            max_cell: IVec2::MIN,
+        }
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Celda que contiene el punto
+    pub fn cell_of(&self, point: Vec2) -> IVec2 {
+        (point / self.cell_size).floor().as_ivec2()
+    }
+
+    /// Ids guardados en una celda
+    pub fn cell(&self, cell: IVec2) -> impl Iterator<Item = SpatialId> + '_ {
+        self.cells
+            .get(&cell)
+            .into_iter()
+            .flatten()
+            .map(|&index| SpatialId(index))
+    }
+
+    fn link(&mut self, index: usize, position: Vec2) {
+        let cell = self.cell_of(position);
+        self.cells.entry(cell).or_default().push(index);
+        self.min_cell = self.min_cell.min(cell);
+        self.max_cell = self.max_cell.max(cell);
+    }
+
+    fn unlink(&mut self, index: usize, position: Vec2) {
+        let cell = self.cell_of(position);
+        if let Some(items) = self.cells.get_mut(&cell) {
+            if let Some(slot) = items.iter().position(|&item| item == index) {
+                items.swap_remove(slot);
+            }
+            if items.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+
+    // Recorre las celdas ocupadas del rectángulo de celdas `min..=max`
+    fn visit_cells(&self, min: IVec2, max: IVec2, mut visit: impl FnMut(usize)) {
+        let (min, max) = (min.max(self.min_cell), max.min(self.max_cell));
+        if min.cmpgt(max).any() {
+            return;
+        }
+        // Si el rectángulo tiene más celdas que las ocupadas, se recorren éstas
+        let area = max.as_i64vec2() - min.as_i64vec2() + I64Vec2::ONE;
+        if area.x * area.y > self.cells.len() as i64 {
+            for (cell, items) in &self.cells {
+                if cell.cmpge(min).all() && cell.cmple(max).all() {
+                    items.iter().for_each(|&index| visit(index));
+                }
+            }
+            return;
+        }
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if let Some(items) = self.cells.get(&IVec2::new(x, y)) {
+                    items.iter().for_each(|&index| visit(index));
+                }
+            }
+        }
+    }
+}
+
+impl<T> SpatialIndex<T> for HashGrid<T> {
+    fn insert(&mut self, position: Vec2, value: T) -> SpatialId {
+        let index = self.slots.insert(position, value);
+        self.link(index, position);
+        SpatialId(index)
+    }
+
+    fn remove(&mut self, id: SpatialId) -> Option<(Vec2, T)> {
+        let (position, value) = self.slots.remove(id.0)?;
+        self.unlink(id.0, position);
+        Some((position, value))
+    }
+
+    fn set_position(&mut self, id: SpatialId, position: Vec2) -> bool {
+        let Some(previous) = self.slots.set_position(id.0, position) else {
+            return false;
+        };
+        if self.cell_of(previous) != self.cell_of(position) {
+            self.unlink(id.0, previous);
+            self.link(id.0, position);
+        }
+        true
+    }
+
+    fn get(&self, id: SpatialId) -> Option<(Vec2, &T)> {
+        self.slots.get(id.0)
+    }
+
+    fn get_mut(&mut self, id: SpatialId) -> Option<&mut T> {
+        self.slots.get_mut(id.0)
+    }
+
+    fn len(&self) -> usize {
+        self.slots.len
+    }
+
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.slots.clear();
+        self.min_cell = IVec2::MAX;
+        self.max_cell = IVec2::MIN;
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (SpatialId, Vec2, &'a T)>
+    where
+        T: 'a,
+    {
+        self.slots.iter()
+    }
+
+    /// Busca en anillos de celdas alrededor del punto hasta que ningún anillo
+    /// siguiente pueda mejorar el k-ésimo candidato
+    fn nearest_k(&self, point: Vec2, k: usize) -> Vec<SpatialId> {
+        let mut nearest = NearestK::new(k.min(self.len()));
+        if nearest.k == 0 {
+            return vec![];
+        }
+        // Un punto lejano satura `cell_of`; se acerca a las celdas ocupadas para
+        // no desbordar. Los anillos desde ahí nunca sobreestiman la distancia real
+        let center = self.cell_of(point).clamp(self.min_cell, self.max_cell);
+
+        // Anillos de Chebyshev que tocan las celdas ocupadas
+        let last = center
+            .saturating_sub(self.min_cell)
+            .max(self.max_cell.saturating_sub(center))
+            .max_element();
+
+        for ring in 0..=last {
+            let (min, max) = (
+                center.saturating_sub(IVec2::splat(ring)),
+                center.saturating_add(IVec2::splat(ring)),
+            );
+            // Filas superior e inferior completas, columnas laterales sin esquinas
+            let sides = if ring == 0 {
+                vec![(center, center)]
+            } else {
+                vec![
+                    (min, IVec2::new(max.x, min.y)),
+                    (IVec2::new(min.x, max.y), max),
+                    (IVec2::new(min.x, min.y + 1), IVec2::new(min.x, max.y - 1)),
+                    (IVec2::new(max.x, min.y + 1), IVec2::new(max.x, max.y - 1)),
+                ]
+            };
+            for (from, to) in sides {
+                self.visit_cells(from, to, |index| {
+                    if let Some(position) = self.slots.position(index) {
+                        nearest.offer(position.distance_squared(point), index);
+                    }
+                });
+            }
+            // Todo lo que queda fuera está al menos a `ring` celdas
+            let reach = ring as f32 * self.cell_size;
+            if nearest.bound() <= reach * reach {
+                break;
+            }
+        }
+        nearest.into_ids()
+    }
+
+    fn within_radius(&self, center: Vec2, radius: f32) -> Vec<SpatialId> {
+        let radius_squared = radius * radius;
+        let mut found = vec![];
+        self.visit_cells(
+            self.cell_of(center - Vec2::splat(radius)),
+            self.cell_of(center + Vec2::splat(radius)),
+            |index| {
+                if let Some(position) = self.slots.position(index)
+                    && position.distance_squared(center) <= radius_squared
+                {
+                    found.push(SpatialId(index));
+                }
+            },
+        );
+        found
+    }
+
+    fn within_rect(&self, min: Vec2, max: Vec2) -> Vec<SpatialId> {
+        let mut found = vec![];
+        self.visit_cells(self.cell_of(min), self.cell_of(max), |index| {
+            if let Some(position) = self.slots.position(index)
+                && in_rect(position, min, max)
+            {
+                found.push(SpatialId(index));
+            }
+        });
+        found
+    }
+}
+
+#[derive(Debug, Clone)]
+struct QuadNode {
+    min: Vec2,
+    max: Vec2,
+    items: Vec<usize>,
+    children: Option<Box<[QuadNode; 4]>>,
+}
+
+impl QuadNode {
+    fn new(min: Vec2, max: Vec2) -> Self {
+        Self {
+            min,
+            max,
+            items: vec![],
+            children: None,
+        }
+    }
+
+    // Cuadrante: bit 0 a la derecha del centro, bit 1 por debajo
+    fn quadrant(&self, point: Vec2) -> usize {
+        let center = (self.min + self.max) * 0.5;
+        (point.x >= center.x) as usize + 2 * (point.y >= center.y) as usize
+    }
+
+    fn split(&mut self) {
+        let (min, max) = (self.min, self.max);
+        let center = (min + max) * 0.5;
+        self.children = Some(Box::new([
+            QuadNode::new(min, center),
+            QuadNode::new(Vec2::new(center.x, min.y), Vec2::new(max.x, center.y)),
+            QuadNode::new(Vec2::new(min.x, center.y), Vec2::new(center.x, max.y)),
+            QuadNode::new(center, max),
+        ]));
+    }
+
+    fn insert<T>(&mut self, index: usize, point: Vec2, slots: &Slots<T>, limits: (usize, usize)) {
+        let (capacity, depth) = limits;
+        let quadrant = self.quadrant(point);
+        if let Some(children) = &mut self.children {
+            children[quadrant].insert(index, point, slots, (capacity, depth.saturating_sub(1)));
+            return;
+        }
+
+        self.items.push(index);
+        if self.items.len() > capacity && depth > 0 {
+            self.split();
+            for item in std::mem::take(&mut self.items) {
+                let position = slots.position(item).unwrap_or(point);
+                let quadrant = self.quadrant(position);
+                if let Some(children) = &mut self.children {
+                    children[quadrant].insert(item, position, slots, (capacity, depth - 1));
+                }
+            }
+        }
+    }
+
+    /// Quita el elemento y fusiona los hijos que queden casi vacíos
+    fn remove(&mut self, index: usize, point: Vec2, capacity: usize) -> bool {
+        let quadrant = self.quadrant(point);
+        let Some(children) = &mut self.children else {
+            let Some(slot) = self.items.iter().position(|&item| item == index) else {
+                return false;
+            };
+            self.items.swap_remove(slot);
+            return true;
+        };
+
+        if !children[quadrant].remove(index, point, capacity) {
+            return false;
+        }
+        let leaves = children.iter().all(|child| child.children.is_none());
+        let total: usize = children.iter().map(|child| child.items.len()).sum();
+        if leaves && total <= capacity {
+            let children = self
+                .children
+                .take()
+                .into_iter()
+                .flat_map(|children| *children);
+            self.items = children.flat_map(|child| child.items).collect();
+        }
+        true
+    }
+
+    fn within<T>(
+        &self,
+        min: Vec2,
+        max: Vec2,
+        slots: &Slots<T>,
+        test: &dyn Fn(Vec2) -> bool,
+        found: &mut Vec<SpatialId>,
+    ) {
+        if self.max.cmplt(min).any() || self.min.cmpgt(max).any() {
+            return;
+        }
+        for &index in &self.items {
+            if slots.position(index).is_some_and(test) {
+                found.push(SpatialId(index));
+            }
+        }
+        for child in self.children.iter().flat_map(|children| children.iter()) {
+            child.within(min, max, slots, test, found);
+        }
+    }
+
+    fn nearest<T>(&self, point: Vec2, slots: &Slots<T>, nearest: &mut NearestK) {
+        if box_distance_squared(point, self.min, self.max) > nearest.bound() {
+            return;
+        }
+        for &index in &self.items {
+            if let Some(position) = slots.position(index) {
+                nearest.offer(position.distance_squared(point), index);
+            }
+        }
+        if let Some(children) = &self.children {
+            // Primero los cuadrantes más cercanos, para podar antes al resto
+            let mut order: [usize; 4] = [0, 1, 2, 3];
+            order.sort_by_key(|&i| {
+                Float(box_distance_squared(
+                    point,
+                    children[i].min,
+                    children[i].max,
+                ))
+            });
+            for i in order {
+                children[i].nearest(point, slots, nearest);
+            }
+        }
+    }
+}
+
+/// Quadtree de puntos: subdivide donde hay densidad, útil con distribuciones
+/// irregulares. Los límites crecen solos si se inserta fuera de ellos.
+#[derive(Debug, Clone)]
+pub struct QuadTree<T> {
+    root: QuadNode,
+    slots: Slots<T>,
+    capacity: usize,
+    max_depth: usize,
+}
+
+impl<T> QuadTree<T> {
+    pub fn new(min: Vec2, max: Vec2) -> Self {
+        Self {
+            root: QuadNode::new(min.min(max), min.max(max)),
+            slots: Slots::default(),
+            capacity: 8,
+            max_depth: 16,
+        }
+    }
+
+    /// Elementos por hoja antes de subdividir
+    pub fn with_node_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self.rebuild();
+        self
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self.rebuild();
+        self
+    }
+
+    pub fn bounds(&self) -> (Vec2, Vec2) {
+        (self.root.min, self.root.max)
+    }
+
+    fn limits(&self) -> (usize, usize) {
+        (self.capacity, self.max_depth)
+    }
+
+    fn rebuild(&mut self) {
+        self.root = QuadNode::new(self.root.min, self.root.max);
+        let items: Vec<(usize, Vec2)> = self
+            .slots
+            .iter()
+            .map(|(id, position, _)| (id.0, position))
+            .collect();
+        let limits = self.limits();
+        for (index, position) in items {
+            self.root.insert(index, position, &self.slots, limits);
+        }
+    }
+
+    // Duplica los límites hacia el punto hasta contenerlo
+    fn grow_to(&mut self, point: Vec2) {
+        if !point.is_finite() || in_rect(point, self.root.min, self.root.max) {
+            return;
+        }
+        let (mut min, mut max) = (self.root.min, self.root.max);
+        while !in_rect(point, min, max) {
+            let size = (max - min).max(Vec2::ONE);
+            if point.x < min.x || point.y < min.y {
+                min -= size;
+            } else {
+                max += size;
+            }
+        }
+        self.root.min = min;
+        self.root.max = max;
+        self.rebuild();
+    }
+}
+
+impl<T> SpatialIndex<T> for QuadTree<T> {
+    fn insert(&mut self, position: Vec2, value: T) -> SpatialId {
+        self.grow_to(position);
+        let index = self.slots.insert(position, value);
+        let limits = self.limits();
+        self.root.insert(index, position, &self.slots, limits);
+        SpatialId(index)
+    }
+
+    fn remove(&mut self, id: SpatialId) -> Option<(Vec2, T)> {
+        let position = self.slots.position(id.0)?;
+        self.root.remove(id.0, position, self.capacity);
+        self.slots.remove(id.0)
+    }
+
+    fn set_position(&mut self, id: SpatialId, position: Vec2) -> bool {
+        let Some(previous) = self.slots.position(id.0) else {
+            return false;
+        };
+        self.root.remove(id.0, previous, self.capacity);
+        self.slots.set_position(id.0, position);
+        // Al crecer se reconstruye desde los slots, que ya tienen la posición nueva
+        if position.is_finite() && !in_rect(position, self.root.min, self.root.max) {
+            self.grow_to(position);
+        } else {
+            let limits = self.limits();
+            self.root.insert(id.0, position, &self.slots, limits);
+        }
+        true
+    }
+
+    fn get(&self, id: SpatialId) -> Option<(Vec2, &T)> {
+        self.slots.get(id.0)
+    }
+
+    fn get_mut(&mut self, id: SpatialId) -> Option<&mut T> {
+        self.slots.get_mut(id.0)
+    }
+
+    fn len(&self) -> usize {
+        self.slots.len
+    }
+
+    fn clear(&mut self) {
+        self.slots.clear();
+        self.root = QuadNode::new(self.root.min, self.root.max);
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (SpatialId, Vec2, &'a T)>
+    where
+        T: 'a,
+    {
+        self.slots.iter()
+    }
+
+    fn nearest_k(&self, point: Vec2, k: usize) -> Vec<SpatialId> {
+        let mut nearest = NearestK::new(k.min(self.len()));
+        if nearest.k > 0 {
+            self.root.nearest(point, &self.slots, &mut nearest);
+        }
+        nearest.into_ids()
+    }
+
+    fn within_radius(&self, center: Vec2, radius: f32) -> Vec<SpatialId> {
+        let mut found = vec![];
+        let radius_squared = radius * radius;
+        self.root.within(
+            center - Vec2::splat(radius),
+            center + Vec2::splat(radius),
+            &self.slots,
+            &|position| position.distance_squared(center) <= radius_squared,
+            &mut found,
+        );
+        found
+    }
+
+    fn within_rect(&self, min: Vec2, max: Vec2) -> Vec<SpatialId> {
+        let mut found = vec![];
+        self.root.within(
+            min,
+            max,
+            &self.slots,
+            &|position| in_rect(position, min, max),
+            &mut found,
+        );
+        found
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct KdNode {
+    // `None` cuando el elemento se quitó o se movió a otro nodo
+    item: Option<usize>,
+    position: Vec2,
+    axis: usize,
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+/// Árbol k-d de puntos: las consultas de vecinos más rápidas cuando los puntos
+/// cambian poco. Las inserciones y borrados se acumulan y el árbol se reequilibra
+/// solo cuando se degrada.
+#[derive(Debug, Clone)]
+pub struct KdTree<T> {
+    nodes: Vec<KdNode>,
+    root: Option<usize>,
+    slots: Slots<T>,
+    // Nodo vivo de cada id
+    node_of: Vec<usize>,
+    // Cambios desde el último equilibrado
+    pending: usize,
+}
+
+impl<T> Default for KdTree<T> {
+    fn default() -> Self {
+        Self {
+            nodes: vec![],
+            root: None,
+            slots: Slots::default(),
+            node_of: vec![],
+            pending: 0,
+        }
+    }
+}
+
+impl<T> KdTree<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Árbol equilibrado desde el principio
+    pub fn from_points(points: impl IntoIterator<Item = (Vec2, T)>) -> Self {
+        let mut tree = Self::new();
+        for (position, value) in points {
+            tree.slots.insert(position, value);
+        }
+        tree.rebuild();
+        tree
+    }
+
+    /// Reconstruye el árbol equilibrado con la mediana de cada eje
+    pub fn rebuild(&mut self) {
+        let mut items: Vec<(usize, Vec2)> = self
+            .slots
+            .iter()
+            .map(|(id, position, _)| (id.0, position))
+            .collect();
+        self.nodes.clear();
+        self.nodes.reserve(items.len());
+        self.node_of.resize(self.slots.items.len(), 0);
+        self.root = self.build(&mut items, 0);
+        self.pending = 0;
+    }
+
+    fn build(&mut self, items: &mut [(usize, Vec2)], depth: usize) -> Option<usize> {
+        if items.is_empty() {
+            return None;
+        }
+        let axis = depth % 2;
+        let middle = items.len() / 2;
+        items.select_nth_unstable_by(middle, |a, b| a.1[axis].total_cmp(&b.1[axis]));
+        let (index, position) = items[middle];
+
+        let node = self.nodes.len();
+        self.nodes.push(KdNode {
+            item: Some(index),
+            position,
+            axis,
+            left: None,
+            right: None,
+        });
+        self.node_of[index] = node;
+
+        let (left, rest) = items.split_at_mut(middle);
+        self.nodes[node].left = self.build(left, depth + 1);
+        self.nodes[node].right = self.build(&mut rest[1..], depth + 1);
+        Some(node)
+    }
+
+    // Cuelga un nodo nuevo bajo la hoja que le corresponde
+    fn attach(&mut self, index: usize, position: Vec2) {
+        let node = self.nodes.len();
+        if self.node_of.len() <= index {
+            self.node_of.resize(index + 1, 0);
+        }
+        self.node_of[index] = node;
+
+        let mut axis = 0;
+        let mut parent = self.root;
+        while let Some(current) = parent {
+            let current = &mut self.nodes[current];
+            axis = 1 - current.axis;
+            let branch = if position[current.axis] < current.position[current.axis] {
+                &mut current.left
+            } else {
+                &mut current.right
+            };
+            match branch {
+                Some(next) => parent = Some(*next),
+                None => {
+                    *branch = Some(node);
+                    break;
+                }
+            }
+        }
+        if self.root.is_none() {
+            self.root = Some(node);
+        }
+        self.nodes.push(KdNode {
+            item: Some(index),
+            position,
+            axis,
+            left: None,
+            right: None,
+        });
+    }
+
+    // Reequilibra cuando los cambios superan a la mitad de los elementos
+    fn touch(&mut self) {
+        self.pending += 1;
+        if self.pending > self.len() / 2 + 16 {
+            self.rebuild();
+        }
+    }
+
+    fn detach(&mut self, index: usize) {
+        if let Some(&node) = self.node_of.get(index)
+            && let Some(node) = self.nodes.get_mut(node)
+            && node.item == Some(index)
+        {
+            node.item = None;
+        }
+    }
+
+    // Recorre los nodos sin recursión: las ramas insertadas pueden ser profundas
+    fn search(&self, mut visit: impl FnMut(&KdNode) -> (bool, bool)) {
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            let (left, right) = visit(node);
+            if left && let Some(next) = node.left {
+                stack.push(next);
+            }
+            if right && let Some(next) = node.right {
+                stack.push(next);
+            }
+        }
+    }
+}
+
+impl<T> SpatialIndex<T> for KdTree<T> {
+    fn insert(&mut self, position: Vec2, value: T) -> SpatialId {
+        let index = self.slots.insert(position, value);
+        self.attach(index, position);
+        self.touch();
+        SpatialId(index)
+    }
+
+    fn remove(&mut self, id: SpatialId) -> Option<(Vec2, T)> {
+        let item = self.slots.remove(id.0)?;
+        self.detach(id.0);
+        self.touch();
+        Some(item)
+    }
+
+    fn set_position(&mut self, id: SpatialId, position: Vec2) -> bool {
+        if self.slots.set_position(id.0, position).is_none() {
+            return false;
+        }
+        self.detach(id.0);
+        self.attach(id.0, position);
+        self.touch();
+        true
+    }
+
+    fn get(&self, id: SpatialId) -> Option<(Vec2, &T)> {
+        self.slots.get(id.0)
+    }
+
+    fn get_mut(&mut self, id: SpatialId) -> Option<&mut T> {
+        self.slots.get_mut(id.0)
+    }
+
+    fn len(&self) -> usize {
+        self.slots.len
+    }
+
+    fn clear(&mut self) {
+        self.slots.clear();
+        self.nodes.clear();
+        self.node_of.clear();
+        self.root = None;
+        self.pending = 0;
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (SpatialId, Vec2, &'a T)>
+    where
+        T: 'a,
+    {
+        self.slots.iter()
+    }
+
+    fn nearest_k(&self, point: Vec2, k: usize) -> Vec<SpatialId> {
+        let mut nearest = NearestK::new(k.min(self.len()));
+        if nearest.k == 0 {
+            return vec![];
+        }
+        // Pila con la distancia mínima posible de cada rama
+        let mut stack: Vec<(usize, f32)> = self.root.map(|root| (root, 0.0)).into_iter().collect();
+        while let Some((node, bound)) = stack.pop() {
+            if bound > nearest.bound() {
+                continue;
+            }
+            let node = &self.nodes[node];
+            if let Some(index) = node.item {
+                nearest.offer(node.position.distance_squared(point), index);
+            }
+            let difference = point[node.axis] - node.position[node.axis];
+            let (near, far) = if difference < 0.0 {
+                (node.left, node.right)
+            } else {
+                (node.right, node.left)
+            };
+            // La rama cercana se apila al final para visitarla primero
+            if let Some(far) = far {
+                stack.push((far, bound.max(difference * difference)));
+            }
+            if let Some(near) = near {
+                stack.push((near, bound));
+            }
+        }
+        nearest.into_ids()
+    }
+
+    fn within_radius(&self, center: Vec2, radius: f32) -> Vec<SpatialId> {
+        let radius_squared = radius * radius;
+        let mut found = vec![];
+        self.search(|node| {
+            if let Some(index) = node.item
+                && node.position.distance_squared(center) <= radius_squared
+            {
+                found.push(SpatialId(index));
+            }
+            let difference = center[node.axis] - node.position[node.axis];
+            (difference <= radius, difference >= -radius)
+        });
+        found
+    }
+
+    fn within_rect(&self, min: Vec2, max: Vec2) -> Vec<SpatialId> {
+        let mut found = vec![];
+        self.search(|node| {
+            if let Some(index) = node.item
+                && in_rect(node.position, min, max)
+            {
+                found.push(SpatialId(index));
+            }
+            let split = node.position[node.axis];
+            (min[node.axis] <= split, max[node.axis] >= split)
+        });
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use rand::Rng;
+
+    use super::*;
+    use crate::random::GalarRng;
+
+    // Modelo de referencia: ids vivos con su posición, consultados por fuerza bruta
+    struct Checker {
+        rng: GalarRng,
+        alive: HashMap<SpatialId, Vec2>,
+    }
+
+    impl Checker {
+        fn new() -> Self {
+            Self {
+                rng: GalarRng::new(0x5EED),
+                alive: HashMap::new(),
+            }
+        }
+
+        fn point(&mut self) -> Vec2 {
+            // Algunos puntos repetidos en una rejilla gruesa para forzar empates
+            if self.rng.chance(0.2) {
+                Vec2::new(
+                    self.rng.random_range(-3..3) as f32 * 10.0,
+                    self.rng.random_range(-3..3) as f32 * 10.0,
+                )
+            } else {
+                Vec2::new(
+                    self.rng.random_range(-50.0..50.0),
+                    self.rng.random_range(-50.0..50.0),
+                )
+            }
+        }
+
+        fn insert(&mut self, index: &mut impl SpatialIndex<u32>, count: usize) {
+            for _ in 0..count {
+                let position = self.point();
+                let value = self.rng.random();
+                let id = index.insert(position, value);
+                assert!(
+                    self.alive.insert(id, position).is_none(),
+                    "id {id:?} in use"
+                );
+                assert_eq!(index.get(id), Some((position, &value)));
+            }
+        }
+
+        fn remove(&mut self, index: &mut impl SpatialIndex<u32>, count: usize) -> Vec<SpatialId> {
+            let mut ids: Vec<SpatialId> = self.alive.keys().copied().collect();
+            ids.sort();
+            let removed: Vec<SpatialId> = ids.into_iter().step_by(3).take(count).collect();
+            for &id in &removed {
+                let position = self.alive.remove(&id).unwrap();
+                assert_eq!(index.remove(id).map(|(p, _)| p), Some(position));
+                assert!(index.get(id).is_none());
+                assert!(index.remove(id).is_none());
+            }
+            removed
+        }
+
+        fn shuffle(&mut self, index: &mut impl SpatialIndex<u32>) {
+            let ids: Vec<SpatialId> = self.alive.keys().copied().collect();
+            for id in ids.into_iter().step_by(4) {
+                let position = self.point();
+                assert!(index.set_position(id, position));
+                self.alive.insert(id, position);
+            }
+        }
+
+        fn check(&mut self, index: &impl SpatialIndex<u32>) {
+            assert_eq!(index.len(), self.alive.len());
+            let listed: HashMap<SpatialId, Vec2> = index
+                .iter()
+                .map(|(id, position, _)| (id, position))
+                .collect();
+            assert_eq!(listed, self.alive);
+
+            let mut queries: Vec<Vec2> = (0..40).map(|_| self.point()).collect();
+            queries.extend([
+                Vec2::new(-1e30, 0.0),
+                Vec2::new(1e30, -1e30),
+                Vec2::new(400.0, 3.0),
+            ]);
+            for query in queries {
+                for k in [1, 7, 30, self.alive.len() + 3] {
+                    self.check_nearest(index, query, k);
+                }
+                let radius = self.rng.random_range(0.0..25.0);
+                let expected = self.brute(|p| p.distance_squared(query) <= radius * radius);
+                assert_eq!(set(index.within_radius(query, radius)), expected);
+
+                let corner = query + Vec2::new(self.rng.random_range(0.0..40.0), 30.0);
+                let expected = self.brute(|p| in_rect(p, query, corner));
+                assert_eq!(set(index.within_rect(query, corner)), expected);
+            }
+            let everything = self.brute(|_| true);
+            assert_eq!(set(index.within_radius(Vec2::ZERO, 1e30)), everything);
+        }
+
+        fn check_nearest(&self, index: &impl SpatialIndex<u32>, query: Vec2, k: usize) {
+            let found = index.nearest_k(query, k);
+            let mut expected: Vec<f32> = self
+                .alive
+                .values()
+                .map(|p| p.distance_squared(query))
+                .collect();
+            expected.sort_by(f32::total_cmp);
+            expected.truncate(k);
+
+            // Con empates los ids pueden variar, las distancias no
+            assert_eq!(set(found.clone()).len(), found.len());
+            let distances: Vec<f32> = found
+                .iter()
+                .map(|id| self.alive[id].distance_squared(query))
+                .collect();
+            assert_eq!(distances, expected, "k = {k} at {query}");
+            let nearest = index
+                .nearest(query)
+                .map(|id| self.alive[&id].distance_squared(query));
+            assert_eq!(nearest, expected.first().copied());
+        }
+
+        fn brute(&self, filter: impl Fn(Vec2) -> bool) -> HashSet<SpatialId> {
+            self.alive
+                .iter()
+                .filter(|(_, position)| filter(**position))
+                .map(|(id, _)| *id)
+                .collect()
+        }
+    }
+
+    fn set(ids: Vec<SpatialId>) -> HashSet<SpatialId> {
+        ids.into_iter().collect()
+    }
+
+    fn exercise(mut index: impl SpatialIndex<u32>) {
+        let mut checker = Checker::new();
+        assert!(index.is_empty());
+        assert!(index.nearest(Vec2::ZERO).is_none());
+        assert!(index.within_radius(Vec2::ZERO, 10.0).is_empty());
+
+        checker.insert(&mut index, 300);
+        checker.check(&index);
+
+        let removed = checker.remove(&mut index, 80);
+        checker.check(&index);
+
+        checker.shuffle(&mut index);
+        checker.check(&index);
+
+        // Los huecos se reutilizan con los ids quitados
+        checker.insert(&mut index, 120);
+        let reused = removed.iter().filter(|id| checker.alive.contains_key(id));
+        assert_eq!(reused.count(), removed.len());
+        checker.check(&index);
+
+        *index.get_mut(removed[0]).unwrap() = 7;
+        assert_eq!(index.get(removed[0]).map(|(_, v)| *v), Some(7));
+
+        index.clear();
+        checker.alive.clear();
+        checker.check(&index);
+        checker.insert(&mut index, 10);
+        checker.check(&index);
+    }
+
+    #[test]
+    fn hash_grid_matches_brute_force() {
+        exercise(HashGrid::new(7.5));
+    }
+
+    #[test]
+    fn quad_tree_matches_brute_force() {
+        // Límites pequeños: el árbol tiene que crecer
+        exercise(QuadTree::new(Vec2::splat(-5.0), Vec2::splat(5.0)).with_node_capacity(4));
+    }
+
+    #[test]
+    fn kd_tree_matches_brute_force() {
+        exercise(KdTree::new());
+    }
+
+    #[test]
+    fn kd_tree_from_points() {
+        let tree = KdTree::from_points((0..50).map(|i| (Vec2::new(i as f32, 0.0), i)));
+        let nearest = tree.nearest_k(Vec2::new(10.2, 5.0), 3);
+        let values: Vec<i32> = nearest.iter().map(|&id| *tree.get(id).unwrap().1).collect();
+        assert_eq!(values, [10, 11, 9]);
+    }
+}
@@ -0,0 +1,588 @@
+use std::collections::HashMap;
+
+use glam::Vec2;
+
+use super::{
+    animation::LoopMode,
+    assets::Assets,
+    canvas::Canvas,
+    colores::Color,
+    draws::draw_shape,
+    shape::{Shape, Vertex},
+    texture::{Material, TextureHandle},
+    transform::Transform,
+    utils::{ConfigGalar, PluginGalar},
+};
+
+/// Región rectangular de una textura, en píxeles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FrameRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl FrameRect {
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Esquinas UV (mín, máx) de la región dentro de una textura de tamaño `texture_size`.
+    /// Se recortan un poco para que el muestreo no toque el frame vecino.
+    pub fn uv(&self, (tex_w, tex_h): (u32, u32)) -> (Vec2, Vec2) {
+        const INSET: f32 = 0.001;
+        let size = Vec2::new(tex_w.max(1) as f32, tex_h.max(1) as f32);
+        let min = Vec2::new(self.x as f32 + INSET, self.y as f32 + INSET) / size;
+        let max = Vec2::new(
+            (self.x + self.width) as f32 - INSET,
+            (self.y + self.height) as f32 - INSET,
+        ) / size;
+        (min, max)
+    }
+}
+
+/// Textura dividida en frames
+#[derive(Debug, Clone)]
+pub struct SpriteSheet {
+    pub texture: TextureHandle,
+    pub frames: Vec<FrameRect>,
+}
+
+impl SpriteSheet {
+    /// Divide la textura en una rejilla, frames ordenados por filas
+    pub fn from_grid(texture: impl Into<TextureHandle>, frame_width: u32, frame_height: u32) -> Self {
+        Self::from_grid_spaced(texture, frame_width, frame_height, 0, 0)
+    }
+
+    /// Rejilla con `margin` alrededor de la imagen y `spacing` entre frames
+    pub fn from_grid_spaced(
+        texture: impl Into<TextureHandle>,
+        frame_width: u32,
+        frame_height: u32,
+        margin: u32,
+        spacing: u32,
+    ) -> Self {
+        let texture = texture.into();
+        let (width, height) = texture.size();
+        let mut frames = Vec::new();
+
+        if frame_width > 0 && frame_height > 0 {
+            let mut y = margin;
+            while y + frame_height <= height.saturating_sub(margin) {
+                let mut x = margin;
+                while x + frame_width <= width.saturating_sub(margin) {
+                    frames.push(FrameRect::new(x, y, frame_width, frame_height));
+                    x += frame_width + spacing;
+                }
+                y += frame_height + spacing;
+            }
+        }
+
+        Self { texture, frames }
+    }
+
+    /// Frames explícitos
+    pub fn from_rects(texture: impl Into<TextureHandle>, frames: Vec<FrameRect>) -> Self {
+        Self {
+            texture: texture.into(),
+            frames,
+        }
+    }
+
+    /// Ruta Absoluta Ej: "src/assets/sheet.png", se carga a través de `Assets::global`
+    pub fn load_grid(path: &str, frame_width: u32, frame_height: u32) -> Result<Self, String> {
+        let texture = Assets::global()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .load_texture(path)?;
+        Ok(Self::from_grid(texture, frame_width, frame_height))
+    }
+
+    pub fn frame(&self, index: usize) -> Option<FrameRect> {
+        self.frames.get(index).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Sprite que muestra el frame `index` (o la textura completa si no existe)
+    pub fn sprite(&self, index: usize) -> Sprite {
+        let (width, height) = self.texture.size();
+        let frame = self.frame(index).unwrap_or(FrameRect::new(0, 0, width, height));
+        Sprite::new(self.texture.clone(), frame)
+    }
+}
+
+/// Región de una textura dibujada como un quad con `draw_shape`
+#[derive(Debug, Clone)]
+pub struct Sprite {
+    shape: Shape,
+    frame: FrameRect,
+    flip_x: bool,
+    flip_y: bool,
+    pivot: Vec2,
+}
+
+impl Sprite {
+    pub fn new(texture: impl Into<TextureHandle>, frame: FrameRect) -> Self {
+        let mut shape = Shape::quad(0);
+        shape.material = Material::from_texture(texture);
+
+        let mut sprite = Self {
+            shape,
+            frame,
+            flip_x: false,
+            flip_y: false,
+            pivot: Vec2::ZERO,
+        };
+        sprite.rebuild();
+        sprite
+    }
+
+    /// Sprite con la textura completa
+    pub fn from_texture(texture: impl Into<TextureHandle>) -> Self {
+        let texture = texture.into();
+        let (width, height) = texture.size();
+        Self::new(texture, FrameRect::new(0, 0, width, height))
+    }
+
+    pub fn with_position(mut self, x: f32, y: f32) -> Self {
+        self.set_position(x, y);
+        self
+    }
+    pub fn with_rotation(mut self, degree: f32) -> Self {
+        self.set_rotation(degree);
+        self
+    }
+    pub fn with_scale(mut self, x: f32, y: f32) -> Self {
+        self.set_scale(x, y);
+        self
+    }
+    pub fn with_flip(mut self, flip_x: bool, flip_y: bool) -> Self {
+        self.set_flip(flip_x, flip_y);
+        self
+    }
+    pub fn with_tint(mut self, tint: Color) -> Self {
+        self.set_tint(Some(tint));
+        self
+    }
+    /// Ancla normalizada: (0, 0) esquina superior izquierda, (0.5, 0.5) centro
+    pub fn with_pivot(mut self, x: f32, y: f32) -> Self {
+        self.set_pivot(x, y);
+        self
+    }
+
+    pub fn set_position(&mut self, x: f32, y: f32) {
+        self.shape.transform.translation = Vec2::new(x, y);
+    }
+    pub fn set_rotation(&mut self, degree: f32) {
+        self.shape.transform.rotation = degree.to_radians();
+    }
+    pub fn set_scale(&mut self, x: f32, y: f32) {
+        self.shape.transform.scale = Vec2::new(x, y);
+    }
+    pub fn set_flip(&mut self, flip_x: bool, flip_y: bool) {
+        self.flip_x = flip_x;
+        self.flip_y = flip_y;
+        self.rebuild();
+    }
+    pub fn set_tint(&mut self, tint: Option<Color>) {
+        self.shape.material.tint = tint;
+    }
+    pub fn set_pivot(&mut self, x: f32, y: f32) {
+        self.pivot = Vec2::new(x, y);
+        self.rebuild();
+    }
+    pub fn set_frame(&mut self, frame: FrameRect) {
+        if self.frame != frame {
+            self.frame = frame;
+            self.rebuild();
+        }
+    }
+
+    pub fn frame(&self) -> FrameRect {
+        self.frame
+    }
+    pub fn flip(&self) -> (bool, bool) {
+        (self.flip_x, self.flip_y)
+    }
+    pub fn transform(&self) -> &Transform {
+        &self.shape.transform
+    }
+    pub fn transform_mut(&mut self) -> &mut Transform {
+        &mut self.shape.transform
+    }
+    pub fn texture(&self) -> Option<&TextureHandle> {
+        self.shape.material.texture.as_ref()
+    }
+    /// Quad texturizado listo para `draw_shape`
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    pub fn draw<C: Canvas + ?Sized>(&self, canvas: &mut C) {
+        draw_shape(canvas, &self.shape);
+    }
+
+    fn rebuild(&mut self) {
+        let width = self.frame.width as f32;
+        let height = self.frame.height as f32;
+        let texture_size = self.texture().map_or((1, 1), |texture| texture.size());
+        let (mut uv_min, mut uv_max) = self.frame.uv(texture_size);
+
+        if self.flip_x {
+            std::mem::swap(&mut uv_min.x, &mut uv_max.x);
+        }
+        if self.flip_y {
+            std::mem::swap(&mut uv_min.y, &mut uv_max.y);
+        }
+
+        let color = Color::WHITE;
+        self.shape.vertices = vec![
+            Vertex::new(0.0, 0.0, color, uv_min),
+            Vertex::new(width, 0.0, color, Vec2::new(uv_max.x, uv_min.y)),
+            Vertex::new(width, height, color, uv_max),
+            Vertex::new(0.0, height, color, Vec2::new(uv_min.x, uv_max.y)),
+        ];
+        self.shape.indices = vec![[0, 1, 2], [0, 2, 3]];
+        self.shape.transform.pivot = self.pivot * Vec2::new(width, height);
+    }
+}
+
+impl PluginGalar for Sprite {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        self.draw(config);
+    }
+}
+
+/// Secuencia de frames con duración individual (en segundos)
+#[derive(Debug, Clone)]
+pub struct SpriteAnimation {
+    pub frames: Vec<usize>,
+    pub durations: Vec<f32>,
+    pub mode: LoopMode,
+}
+
+impl SpriteAnimation {
+    /// Todos los frames duran lo mismo
+    pub fn from_fps(frames: Vec<usize>, fps: f32) -> Self {
+        let duration = if fps > 0.0 { 1.0 / fps } else { f32::INFINITY };
+        let durations = vec![duration; frames.len()];
+        Self {
+            frames,
+            durations,
+            mode: LoopMode::Loop,
+        }
+    }
+
+    /// Frames consecutivos de `from` a `to` (incluido)
+    pub fn range(from: usize, to: usize, fps: f32) -> Self {
+        let frames = if from <= to {
+            (from..=to).collect()
+        } else {
+            (to..=from).rev().collect()
+        };
+        Self::from_fps(frames, fps)
+    }
+
+    pub fn with_mode(mut self, mode: LoopMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.durations.iter().sum()
+    }
+}
+
+// Duración mínima de un frame, en segundos
+const MIN_FRAME_DURATION: f32 = 0.001;
+
+/// Sprite que reproduce secuencias de frames con nombre
+#[derive(Debug, Clone)]
+pub struct AnimatedSprite {
+    sprite: Sprite,
+    frames: Vec<FrameRect>,
+    animations: HashMap<String, SpriteAnimation>,
+    current: Option<String>,
+    step: usize,
+    elapsed: f32,
+    forward: bool,
+    finished: bool,
+}
+
+impl AnimatedSprite {
+    pub fn new(sheet: SpriteSheet) -> Self {
+        let sprite = sheet.sprite(0);
+        Self {
+            sprite,
+            frames: sheet.frames,
+            animations: HashMap::new(),
+            current: None,
+            step: 0,
+            elapsed: 0.0,
+            forward: true,
+            finished: false,
+        }
+    }
+
+    pub fn with_animation(mut self, name: &str, animation: SpriteAnimation) -> Self {
+        self.add_animation(name, animation);
+        self
+    }
+
+    pub fn add_animation(&mut self, name: &str, animation: SpriteAnimation) {
+        self.animations.insert(name.to_string(), animation);
+    }
+
+    /// Reproduce la animación; si ya se estaba reproduciendo no la reinicia
+    pub fn play(&mut self, name: &str) -> Result<(), String> {
+        if self.current.as_deref() != Some(name) {
+            self.restart(name)?;
+        }
+        Ok(())
+    }
+
+    /// Reproduce la animación desde su primer frame
+    pub fn restart(&mut self, name: &str) -> Result<(), String> {
+        if !self.animations.contains_key(name) {
+            return Err(format!("Sprite animation not found: {}", name));
+        }
+        self.current = Some(name.to_string());
+        self.step = 0;
+        self.elapsed = 0.0;
+        self.forward = true;
+        self.finished = false;
+        self.apply_frame();
+        Ok(())
+    }
+
+    pub fn current_animation(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// Índice del frame de la hoja que se está mostrando
+    pub fn current_frame(&self) -> Option<usize> {
+        let animation = self.animations.get(self.current.as_ref()?)?;
+        animation.frames.get(self.step).copied()
+    }
+
+    /// Una animación `Once` llegó a su último frame
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn sprite(&self) -> &Sprite {
+        &self.sprite
+    }
+    pub fn sprite_mut(&mut self) -> &mut Sprite {
+        &mut self.sprite
+    }
+
+    /// Avanza la animación `dt` segundos
+    pub fn update(&mut self, dt: f32) {
+        let Some(animation) = self.current.as_ref().and_then(|name| self.animations.get(name)) else {
+            return;
+        };
+        let len = animation.frames.len();
+        if len == 0 || self.finished {
+            return;
+        }
+
+        self.elapsed += dt;
+        let mut changed = false;
+
+        while !self.finished {
+            // Un frame de duración cero no debe atascar el bucle
+            let duration = animation
+                .durations
+                .get(self.step)
+                .copied()
+                .unwrap_or(f32::INFINITY)
+                .max(MIN_FRAME_DURATION);
+            if self.elapsed < duration {
+                break;
+            }
+            self.elapsed -= duration;
+            changed = true;
+
+            match animation.mode {
+                LoopMode::Once => {
+                    if self.step + 1 < len {
+                        self.step += 1;
+                    } else {
+                        self.finished = true;
+                    }
+                }
+                LoopMode::Loop => self.step = (self.step + 1) % len,
+                LoopMode::PingPong => {
+                    if len == 1 {
+                        continue;
+                    }
+                    if self.forward && self.step + 1 >= len {
+                        self.forward = false;
+                    } else if !self.forward && self.step == 0 {
+                        self.forward = true;
+                    }
+                    self.step = if self.forward { self.step + 1 } else { self.step - 1 };
+                }
+            }
+        }
+
+        if changed {
+            self.apply_frame();
+        }
+    }
+
+    pub fn draw<C: Canvas + ?Sized>(&self, canvas: &mut C) {
+        self.sprite.draw(canvas);
+    }
+
+    fn apply_frame(&mut self) {
+        if let Some(frame) = self.current_frame().and_then(|index| self.frames.get(index)) {
+            self.sprite.set_frame(*frame);
+        }
+    }
+}
+
+impl PluginGalar for AnimatedSprite {
+    fn update(&mut self, config: &mut ConfigGalar) {
+        AnimatedSprite::update(self, config.delta_time());
+        self.draw(config);
+    }
+}
+
+#[cfg(feature = "aseprite")]
+mod aseprite {
+    use std::path::Path;
+
+    use serde::Deserialize;
+
+    use super::{AnimatedSprite, FrameRect, SpriteAnimation, SpriteSheet};
+    use crate::{animation::LoopMode, assets::Assets};
+
+    #[derive(Deserialize)]
+    struct AseRect {
+        x: u32,
+        y: u32,
+        w: u32,
+        h: u32,
+    }
+
+    #[derive(Deserialize)]
+    struct AseFrame {
+        frame: AseRect,
+        #[serde(default = "default_duration")]
+        duration: u32, // milisegundos
+    }
+
+    fn default_duration() -> u32 {
+        100
+    }
+
+    /// Aseprite exporta los frames como arreglo o como mapa ("Hash")
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AseFrames {
+        Array(Vec<AseFrame>),
+        Hash(serde_json::Map<String, serde_json::Value>),
+    }
+
+    #[derive(Deserialize)]
+    struct AseTag {
+        name: String,
+        from: usize,
+        to: usize,
+        #[serde(default)]
+        direction: String,
+    }
+
+    #[derive(Deserialize)]
+    struct AseMeta {
+        image: String,
+        #[serde(default, rename = "frameTags")]
+        frame_tags: Vec<AseTag>,
+    }
+
+    #[derive(Deserialize)]
+    struct AseFile {
+        frames: AseFrames,
+        meta: AseMeta,
+    }
+
+    impl AnimatedSprite {
+        /// Carga la hoja y las etiquetas exportadas por Aseprite (JSON + imagen).
+        /// Sin etiquetas se crea la animación "default" con todos los frames.
+        pub fn from_aseprite(json_path: &str) -> Result<Self, String> {
+            let text = std::fs::read_to_string(json_path)
+                .map_err(|e| format!("Error reading {}: {}", json_path, e))?;
+            let file: AseFile = serde_json::from_str(&text)
+                .map_err(|e| format!("Error parsing {}: {}", json_path, e))?;
+
+            let frames = match file.frames {
+                AseFrames::Array(frames) => frames,
+                AseFrames::Hash(map) => map
+                    .into_iter()
+                    .map(|(_, value)| serde_json::from_value(value))
+                    .collect::<Result<Vec<AseFrame>, _>>()
+                    .map_err(|e| format!("Error parsing {}: {}", json_path, e))?,
+            };
+
+            let image_path = Path::new(json_path)
+                .parent()
+                .unwrap_or(Path::new(""))
+                .join(&file.meta.image);
+            let texture = Assets::global()
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .load_texture(&image_path.to_string_lossy())?;
+
+            let rects = frames
+                .iter()
+                .map(|f| FrameRect::new(f.frame.x, f.frame.y, f.frame.w, f.frame.h))
+                .collect();
+            let durations: Vec<f32> = frames.iter().map(|f| f.duration as f32 / 1000.0).collect();
+
+            let mut sprite = AnimatedSprite::new(SpriteSheet::from_rects(texture, rects));
+
+            let animation = |indices: Vec<usize>, mode: LoopMode| SpriteAnimation {
+                durations: indices.iter().map(|&i| durations.get(i).copied().unwrap_or(0.1)).collect(),
+                frames: indices,
+                mode,
+            };
+
+            if file.meta.frame_tags.is_empty() {
+                sprite.add_animation("default", animation((0..frames.len()).collect(), LoopMode::Loop));
+                sprite.play("default")?;
+            }
+
+            for tag in &file.meta.frame_tags {
+                let forward: Vec<usize> = (tag.from..=tag.to).collect();
+                let (indices, mode) = match tag.direction.as_str() {
+                    "reverse" => (forward.into_iter().rev().collect(), LoopMode::Loop),
+                    "pingpong" => (forward, LoopMode::PingPong),
+                    "pingpong_reverse" => (forward.into_iter().rev().collect(), LoopMode::PingPong),
+                    _ => (forward, LoopMode::Loop),
+                };
+                sprite.add_animation(&tag.name, animation(indices, mode));
+            }
+
+            if let Some(first) = file.meta.frame_tags.first() {
+                sprite.play(&first.name)?;
+            }
+
+            Ok(sprite)
+        }
+    }
+}
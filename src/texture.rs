@@ -1,4 +1,9 @@
-use super::colores::Color;
+use std::{
+    ops::Deref,
+    sync::{Arc, RwLock},
+};
+
+use super::{assets::Assets, colores::Color, paint::Paint};
 
 #[derive(Debug, Clone)]
 pub struct Texture {
@@ -7,20 +12,26 @@ pub struct Texture {
     pub size: (u32, u32),
 }
 
-#[derive(Debug, Clone, Default)]
-pub struct Material {
-    pub base_color: Option<Color>,
-    pub texture: Option<Texture>,
-    // blend_mode: BlendMode,
-}
+impl Texture {
+    /// Textura en memoria, `data` en formato AARRGGBB
+    pub fn new(width: u32, height: u32, data: Vec<u32>) -> Self {
+        Self {
+            path: String::new(),
+            data,
+            size: (width, height),
+        }
+    }
 
-impl Material {
-    pub fn load_texture(path: &str) -> Material {
-        use image::GenericImageView;
+    /// Decodifica una imagen del disco
+    pub fn load(path: &str) -> Result<Self, String> {
+        let imagen = image::open(path).map_err(|e| format!("Error loading texture {}: {}", path, e))?;
+        let mut texture = Self::from_image(&imagen.to_rgba8());
+        texture.path = path.to_string();
+        Ok(texture)
+    }
 
-        let imagen = image::open(path).expect("Error loading texture");
-        let (width, height) = imagen.dimensions();
-        let rgba_img = imagen.to_rgba8();
+    pub fn from_image(rgba_img: &image::RgbaImage) -> Self {
+        let (width, height) = rgba_img.dimensions();
 
         let mut pixels = Vec::with_capacity((width * height) as usize);
         for pixel in rgba_img.pixels() {
@@ -29,37 +40,137 @@ impl Material {
             pixels.push(color);
         }
 
-        let texture = Texture {
-            path: path.to_string(),
-            data: pixels,
-            size: (width, height),
-        };
+        Self::new(width, height, pixels)
+    }
+
+    pub fn width(&self) -> u32 {
+        self.size.0
+    }
+
+    pub fn height(&self) -> u32 {
+        self.size.1
+    }
+
+    /// Pixel en coordenadas de la textura, `None` fuera de los límites
+    pub fn get_pixel(&self, x: u32, y: u32) -> Option<u32> {
+        if x >= self.size.0 || y >= self.size.1 {
+            return None;
+        }
+        self.data.get((y * self.size.0 + x) as usize).copied()
+    }
+
+    /// Muestreo por vecino más cercano, `uv` en 0.0 .. 1.0
+    pub fn sample(&self, (u, v): (f32, f32)) -> u32 {
+        let (width, height) = self.size;
+        let x = ((u.clamp(0.0, 1.0) * width as f32) as u32).min(width.saturating_sub(1));
+        let y = ((v.clamp(0.0, 1.0) * height as f32) as u32).min(height.saturating_sub(1));
+
+        self.get_pixel(x, y).unwrap_or(0xFF00FFFF) // magenta if out-of-bounds
+    }
+}
+
+/// Referencia compartida a una textura: clonarla no copia los píxeles.
+/// Si la textura se recarga, todos los handles ven el cambio.
+#[derive(Debug, Clone)]
+pub struct TextureHandle(Arc<RwLock<Texture>>);
+
+impl TextureHandle {
+    pub fn new(texture: Texture) -> Self {
+        Self(Arc::new(RwLock::new(texture)))
+    }
+
+    /// Acceso de lectura a la textura
+    pub fn read(&self) -> impl Deref<Target = Texture> + '_ {
+        self.0.read().unwrap_or_else(|e| e.into_inner())
+    }
 
+    /// Sustituye los píxeles para todos los que comparten el handle
+    pub fn replace(&self, texture: Texture) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = texture;
+    }
+
+    pub fn size(&self) -> (u32, u32) {
+        self.read().size
+    }
+
+    /// Indica si ambos handles apuntan a la misma textura
+    pub fn ptr_eq(&self, other: &TextureHandle) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Cantidad de handles vivos que comparten esta textura
+    pub fn ref_count(&self) -> usize {
+        Arc::strong_count(&self.0)
+    }
+}
+
+impl From<Texture> for TextureHandle {
+    fn from(texture: Texture) -> Self {
+        Self::new(texture)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Material {
+    pub base_color: Option<Color>,
+    pub texture: Option<TextureHandle>,
+    /// Color que multiplica a la textura
+    pub tint: Option<Color>,
+    /// Degradado evaluado por píxel; la textura tiene prioridad
+    pub paint: Option<Paint>,
+    // blend_mode: BlendMode,
+}
+
+impl Material {
+    /// Carga la textura a través de la caché global (`Assets::global`):
+    /// la misma ruta se decodifica una sola vez y se comparte.
+    pub fn load_texture(path: &str) -> Material {
+        let texture = Assets::global()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .load_texture(path)
+            .expect("Error loading texture");
+
+        Material::from_texture(texture)
+    }
+    pub fn from_texture(texture: impl Into<TextureHandle>) -> Material {
         Material {
             base_color: None,
-            texture: Some(texture),
+            texture: Some(texture.into()),
+            tint: None,
+            paint: None,
+        }
+    }
+    pub fn from_paint(paint: Paint) -> Material {
+        Material {
+            base_color: None,
+            texture: None,
+            tint: None,
+            paint: Some(paint),
         }
     }
     pub fn sample_texture(&self, uv: (f32, f32)) -> u32 {
-        let (mut u, mut v) = uv;
-
-        u = u.clamp(0.0, 1.0);
-        v = v.clamp(0.0, 1.0);
-
         let mut pixel: u32 = 0;
         if let Some(texture) = &self.texture {
-            let width = texture.size.0;
-            let height = texture.size.1;
-            let x = (u * (width as f32 - 1.0)).round() as u32;
-            let y = (v * (height as f32 - 1.0)).round() as u32;
-
-            let index = (y * width + x) as usize;
-            pixel = texture.data.get(index).copied().unwrap_or(0xFF00FFFF) // magenta if out-of-bounds
+            pixel = texture.read().sample(uv);
+            if let Some(tint) = self.tint {
+                pixel = tint_pixel(pixel, tint);
+            }
         }
         pixel
     }
 }
 
+/// Multiplica cada canal AARRGGBB por el color de tinte
+#[inline(always)]
+pub(crate) fn tint_pixel(pixel: u32, tint: Color) -> u32 {
+    let channel = |shift: u32, factor: u8| -> u32 {
+        let value = (pixel >> shift) & 0xFF;
+        ((value * factor as u32 + 127) / 255) << shift
+    };
+    channel(24, tint.a) | channel(16, tint.r) | channel(8, tint.g) | channel(0, tint.b)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum BlendMode {
     Additive,
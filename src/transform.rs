@@ -1,8 +1,16 @@
+use glam::{Affine2, Mat2, Vec2};
+
+/// Transformación 2D: `T(translation) * R(rotation) * K(skew) * S(scale) * T(-pivot)`
+///
+/// El `pivot` es el punto local sobre el que se escala, sesga y rota;
+/// ese punto termina exactamente en `translation`.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Transform {
-    pub translation: glam::Vec2,
-    pub rotation: f32,   // en radianes
-    pub scale: glam::Vec2, // escala en x e y
+    pub translation: Vec2,
+    pub rotation: f32, // en radianes
+    pub scale: Vec2,   // escala en x e y
+    pub skew: Vec2,    // sesgo en radianes sobre x e y
+    pub pivot: Vec2,   // ancla local
 }
 
 impl Transform {
@@ -12,41 +20,102 @@ impl Transform {
 
     pub fn new(x: f32, y: f32, rotation: f32, scale_x: f32, scale_y: f32) -> Self {
         Self {
-            translation: glam::Vec2 { x, y },
-            rotation: rotation,
-            scale: glam::Vec2 {
+            translation: Vec2 { x, y },
+            rotation,
+            scale: Vec2 {
                 x: scale_x,
                 y: scale_y,
             },
+            ..Default::default()
         }
     }
 
     pub fn from_translation(x: f32, y: f32) -> Self {
         Self {
-            translation: glam::Vec2 { x, y },
-            scale: glam::Vec2::new(1.0, 1.0),
+            translation: Vec2 { x, y },
+            scale: Vec2::new(1.0, 1.0),
             ..Default::default()
         }
     }
-    pub fn apply(&self, local_x: f32, local_y: f32) -> (f32, f32) {
-        let cos_r = self.rotation.cos();
-        let sin_r = self.rotation.sin();
 
-        // escalado y rotación
-        let x = local_x * self.scale.x;
-        let y = local_y * self.scale.y;
+    /// Descompone una matriz afín en traslación, rotación, sesgo en x y escala.
+    ///
+    /// La descomposición es exacta: `Transform::from_affine(m).matrix() == m` (salvo redondeo).
+    pub fn from_affine(affine: Affine2) -> Self {
+        let x_axis = affine.matrix2.x_axis;
+        let y_axis = affine.matrix2.y_axis;
+
+        let scale_x = x_axis.length();
+        let rotation = x_axis.y.atan2(x_axis.x);
+
+        // Eje y sin la rotación: (tan(skew) * sy, sy)
+        let local_y = Vec2::from_angle(-rotation).rotate(y_axis);
+        let scale_y = local_y.y;
+        let skew_x = if scale_y != 0.0 {
+            (local_y.x / scale_y).atan()
+        } else {
+            0.0
+        };
+
+        Self {
+            translation: affine.translation,
+            rotation,
+            scale: Vec2::new(scale_x, scale_y),
+            skew: Vec2::new(skew_x, 0.0),
+            pivot: Vec2::ZERO,
+        }
+    }
+
+    pub fn with_pivot(mut self, x: f32, y: f32) -> Self {
+        self.pivot = Vec2::new(x, y);
+        self
+    }
+
+    pub fn with_skew(mut self, skew_x: f32, skew_y: f32) -> Self {
+        self.skew = Vec2::new(skew_x, skew_y);
+        self
+    }
+
+    /// Matriz precalculada, úsala para transformar muchos puntos
+    pub fn matrix(&self) -> Affine2 {
+        let skew = Mat2::from_cols(
+            Vec2::new(1.0, self.skew.y.tan()),
+            Vec2::new(self.skew.x.tan(), 1.0),
+        );
+        let linear = Mat2::from_angle(self.rotation) * skew * Mat2::from_diagonal(self.scale);
+
+        Affine2 {
+            matrix2: linear,
+            translation: self.translation - linear * self.pivot,
+        }
+    }
+
+    /// Transformación inversa (el pivot queda integrado en la traslación)
+    pub fn inverse(&self) -> Self {
+        Self::from_affine(self.matrix().inverse())
+    }
 
-        // rotación
-        let rotated_x = x * cos_r - y * sin_r;
-        let rotated_y = x * sin_r + y * cos_r;
+    /// Compone `self` después de `other`: primero se aplica `other`
+    pub fn mul_transform(&self, other: &Transform) -> Self {
+        Self::from_affine(self.matrix() * other.matrix())
+    }
 
-        // traslación
-        let final_x = rotated_x + self.translation.x as f32;
-        let final_y = rotated_y + self.translation.y as f32;
+    pub fn apply(&self, local_x: f32, local_y: f32) -> (f32, f32) {
+        self.matrix()
+            .transform_point2(Vec2::new(local_x, local_y))
+            .into()
+    }
 
-        (final_x, final_y)
+    /// Transforma un lote de puntos con una sola matriz
+    pub fn apply_slice(&self, points: &mut [Vec2]) {
+        let matrix = self.matrix();
+        for point in points.iter_mut() {
+            *point = matrix.transform_point2(*point);
+        }
     }
 
+    /// Igual que `apply` pero con el centro del shape como pivot,
+    /// redondeado para evitar desplazamientos de medio píxel.
     pub fn apply_centered(
         &self,
         local_x: f32,
@@ -54,20 +123,41 @@ impl Transform {
         shape_center_x: f32,
         shape_center_y: f32,
     ) -> (f32, f32) {
-        let cos_r = self.rotation.cos();
-        let sin_r = self.rotation.sin();
+        let (x, y) = self
+            .centered(shape_center_x, shape_center_y)
+            .apply(local_x, local_y);
 
-        // Adjust for pixel center alignment
-        let x = (local_x - shape_center_x) * self.scale.x;
-        let y = (local_y - shape_center_y) * self.scale.y;
+        (x.round(), y.round())
+    }
 
-        let rotated_x = x * cos_r - y * sin_r;
-        let rotated_y = x * sin_r + y * cos_r;
+    /// Copia con pivot en `(center_x, center_y)` que deja ese punto en su sitio
+    /// más la traslación, equivalente a `apply_centered` sin redondeo.
+    pub fn centered(&self, center_x: f32, center_y: f32) -> Self {
+        let center = Vec2::new(center_x, center_y);
+        Self {
+            translation: self.translation + center,
+            pivot: center,
+            ..*self
+        }
+    }
+}
+
+impl From<Transform> for Affine2 {
+    fn from(transform: Transform) -> Self {
+        transform.matrix()
+    }
+}
+
+impl From<Affine2> for Transform {
+    fn from(affine: Affine2) -> Self {
+        Transform::from_affine(affine)
+    }
+}
 
-        // Apply rounding correction to avoid pixel shift
-        let final_x = (rotated_x + self.translation.x + shape_center_x).round();
-        let final_y = (rotated_y + self.translation.y + shape_center_y).round();
+impl std::ops::Mul for Transform {
+    type Output = Transform;
 
-        (final_x, final_y)
+    fn mul(self, rhs: Transform) -> Self::Output {
+        self.mul_transform(&rhs)
     }
 }
@@ -1,6 +1,14 @@
-use std::ops::Range;
+use std::time::{Duration, Instant};
 
-use super::colores::Color;
+use super::assets::{AssetEvent, Assets};
+
+use super::camera::Camera2D;
+use super::canvas::{Canvas, slice_fill_span, slice_get, slice_put};
+use super::linear_color::blend_over_opaque;
+use super::postprocess::{PostEffect, PostProcess};
+use super::random::{GalarRng, SEED_ARG, SEED_ENV, set_thread_seed, thread_seed_for};
+use super::render_target::RenderTarget;
+use super::viewport::{BlitColumns, ResizePolicy, ScaleFilter, Viewport, blit_scaled};
 use minifb::{Window, WindowOptions};
 
 pub trait PluginGalar {
@@ -14,6 +22,10 @@ pub trait PluginGalar {
     // Método opcional para limpiar recursos
     fn cleanup(&mut self, _config: &mut ConfigGalar) {}
 
+    // Método opcional, se llama cuando la ventana cambia de tamaño.
+    // Recibe el tamaño de la ventana; `config.size()` ya reporta el tamaño lógico.
+    fn resize(&mut self, _config: &mut ConfigGalar, _width: usize, _height: usize) {}
+
     // Nombre del plugin para depuración
     fn name(&self) -> &str {
         "UnnamedPlugin"
@@ -26,9 +38,27 @@ pub struct ConfigGalar<'g> {
     buffer: &'g mut Vec<u32>,
     width: usize,
     height: usize,
+    viewport: Viewport,
+    delta_time: f32,
+    time: f32,
     pub clean: &'g mut bool,
     pub background: &'g mut u32,
     pub frame_mode: &'g mut FrameMode,
+    pub camera: &'g mut Camera2D,
+    pub post: &'g mut PostProcess,
+    rng: &'g mut GalarRng,
+}
+
+// Estado de Galar que los plugins pueden modificar en cada frame
+struct GalarState {
+    clean: bool,
+    background: u32,
+    frame_mode: FrameMode,
+    camera: Camera2D,
+    post: PostProcess,
+    rng: GalarRng,
+    delta_time: f32,
+    time: f32,
 }
 
 impl<'g> ConfigGalar<'g> {
@@ -36,19 +66,34 @@ impl<'g> ConfigGalar<'g> {
     fn new(
         window: &'g mut Window,
         buffer: &'g mut Vec<u32>,
-        clean: &'g mut bool,
-        background: &'g mut u32,
-        frame_mode: &'g mut FrameMode,
+        (width, height): (usize, usize),
+        viewport: Viewport,
+        state: &'g mut GalarState,
     ) -> Self {
-        let (width, height) = window.get_size();
+        let GalarState {
+            clean,
+            background,
+            frame_mode,
+            camera,
+            post,
+            rng,
+            delta_time,
+            time,
+        } = state;
         Self {
             window,
             buffer,
             clean,
             width,
             height,
+            viewport,
+            delta_time: *delta_time,
+            time: *time,
             background,
             frame_mode,
+            camera,
+            post,
+            rng,
         }
     }
 
@@ -56,26 +101,42 @@ impl<'g> ConfigGalar<'g> {
     fn auto_config(galar: &'g mut Galar) -> Self {
         let window: &'g mut Window = &mut galar.window;
         let buffer: &'g mut Vec<u32> = &mut galar.buffer;
-        let clean: &'g mut bool = &mut galar.clean;
-        let background: &'g mut u32 = &mut galar.background;
-        let frame_mode: &'g mut FrameMode = &mut galar.frame_mode;
-        let (width, height) = window.get_size();
-        Self {
-            window,
-            buffer,
-            clean,
-            width,
-            height,
-            background,
-            frame_mode,
-        }
+        let state: &'g mut GalarState = &mut galar.state;
+        let (width, height) = (galar.width, galar.height);
+        let viewport = galar.viewport;
+        Self::new(window, buffer, (width, height), viewport, state)
     }
 
-    /// Métodos públicos para consulta
+    /// Métodos públicos para consulta: tamaño lógico del buffer
     pub fn size(&self) -> (usize, usize) {
         (self.width, self.height)
     }
 
+    /// Segundos transcurridos desde el frame anterior
+    pub fn delta_time(&self) -> f32 {
+        self.delta_time
+    }
+
+    /// Segundos transcurridos desde que empezó `run`
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Generador aleatorio de Galar; con la misma semilla repite la misma secuencia
+    pub fn rng(&mut self) -> &mut GalarRng {
+        self.rng
+    }
+
+    /// Semilla con la que empezó el generador
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
+    }
+
+    /// Tamaño actual de la ventana, puede diferir del tamaño lógico
+    pub fn window_size(&self) -> (usize, usize) {
+        self.window.get_size()
+    }
+
     /// Mejor semántica: usa set_* para modificadores
     pub fn set_clean_pixels(&mut self, clear: bool) {
         *self.clean = clear;
@@ -91,6 +152,33 @@ impl<'g> ConfigGalar<'g> {
         *self.frame_mode = mode;
     }
 
+    /// Reemplaza la cámara usada por `draw_shape`
+    pub fn set_camera(&mut self, camera: Camera2D) {
+        *self.camera = camera;
+    }
+
+    /// Redirige todo el dibujo (`draw_*`, `draw_shape`) hacia `target` mientras dura `draw`.
+    /// Dentro, `size()` y la cámara son los del target; al salir se refresca su textura.
+    pub fn draw_to<R>(
+        &mut self,
+        target: &mut RenderTarget,
+        draw: impl FnOnce(&mut ConfigGalar<'g>) -> R,
+    ) -> R {
+        let screen_size = (self.width, self.height);
+        (self.width, self.height) = target.size();
+        target.swap_buffer(self.buffer);
+        std::mem::swap(self.camera, &mut target.camera);
+
+        let result = draw(self);
+
+        std::mem::swap(self.camera, &mut target.camera);
+        target.swap_buffer(self.buffer);
+        (self.width, self.height) = screen_size;
+        target.sync_texture();
+
+        result
+    }
+
     /// Control fino sobre la limpieza
     pub fn clear_buffer(&mut self) {
         if *self.clean {
@@ -98,139 +186,89 @@ impl<'g> ConfigGalar<'g> {
         }
     }
 
-    /// Método para dibujar directamente
-    pub fn explicit_draw(&mut self, index: usize, color: u32) {
-        self.buffer[index] = color;
-    }
-
-    /// Iteración sobre una dimención segun la resolución de la pantalla
-    pub fn iter_d1(&self) -> Range<usize> {
-        0..(self.width * self.height)
+    // Acceso controlado a las pulsaciones de teclas
+    pub fn is_key_down(&self, key: minifb::Key) -> bool {
+        self.window.is_key_down(key)
     }
 
-    /// Métodos para dibujar con validación de límites
-    pub fn draw_pixel(&mut self, x: usize, y: usize, color: u32) {
-        if x < self.width && y < self.height {
-            self.buffer[y * self.width + x] = color;
+    // Obtorga las posiciones logicas del Mouse, en píxeles del buffer lógico
+    pub fn get_mouse_position(&mut self, mode: minifb::MouseMode) -> Option<(f32, f32)> {
+        let (x, y) = self.window.get_mouse_pos(minifb::MouseMode::Pass)?;
+        let (lx, ly) = self.viewport.window_to_logical((x, y), (self.width, self.height));
+        let max_x = self.width.saturating_sub(1) as f32;
+        let max_y = self.height.saturating_sub(1) as f32;
+
+        match mode {
+            minifb::MouseMode::Pass => Some((lx, ly)),
+            minifb::MouseMode::Clamp => Some((lx.clamp(0.0, max_x), ly.clamp(0.0, max_y))),
+            minifb::MouseMode::Discard => {
+                if self.viewport.contains(x, y) {
+                    Some((lx.clamp(0.0, max_x), ly.clamp(0.0, max_y)))
+                } else {
+                    None
+                }
+            }
         }
     }
 
-    /// Optimización: dibujar segmentos de línea de manera eficiente
-    pub fn draw_line(&mut self, x0: usize, y0: usize, x1: usize, y1: usize, color: u32) {
-        // Implementación de Bresenham para líneas
-
-        let mut x0 = x0 as isize;
-        let mut y0 = y0 as isize;
-        let x1 = x1 as isize;
-        let y1 = y1 as isize;
-
-        let dx = (x1 - x0).abs();
-        let dy = -(y1 - y0).abs();
-        let sx = if x0 < x1 { 1 } else { -1 };
-        let sy = if y0 < y1 { 1 } else { -1 };
-        let mut err = dx + dy;
-
-        let width = self.width as isize;
-        let height = self.height as isize;
-
-        loop {
-            if x0 >= 0 && x0 < width && y0 >= 0 && y0 < height {
-                self.buffer[(y0 as usize) * self.width + (x0 as usize)] = color;
-            }
-
-            if x0 == x1 && y0 == y1 {
-                break;
-            }
+    // Estado de los botones del Mouse
+    pub fn is_mouse_down(&self, button: minifb::MouseButton) -> bool {
+        self.window.get_mouse_down(button)
+    }
 
-            let e2 = 2 * err;
-            if e2 >= dy {
-                if x0 == x1 {
-                    break;
-                }
-                err += dy;
-                x0 += sx;
-            }
-            if e2 <= dx {
-                if y0 == y1 {
-                    break;
-                }
-                err += dx;
-                y0 += sy;
-            }
-        }
+    // Desplazamiento de la rueda del Mouse en este frame (x, y)
+    pub fn get_scroll_wheel(&self) -> Option<(f32, f32)> {
+        self.window.get_scroll_wheel()
     }
 
-    /// Métodos adicionales para formas rectangulares
-    pub fn draw_rect(&mut self, x: usize, y: usize, width: usize, height: usize, color: u32) {
-        let x_max = (x + width).min(self.width);
-        let y_max = (y + height).min(self.height);
+    // Posición del Mouse en coordenadas del mundo, según la cámara actual
+    pub fn get_mouse_world_position(&mut self, mode: minifb::MouseMode) -> Option<glam::Vec2> {
+        let (x, y) = self.get_mouse_position(mode)?;
+        Some(self.camera.screen_to_world(glam::Vec2::new(x, y)))
+    }
 
-        for curr_y in y..y_max {
-            for curr_x in x..x_max {
-                self.buffer[curr_y * self.width + curr_x] = color;
-            }
-        }
+    // Posición del Mouse en coordenadas de la ventana, sin mapear
+    pub fn get_window_mouse_position(&mut self, mode: minifb::MouseMode) -> Option<(f32, f32)> {
+        self.window.get_mouse_pos(mode)
     }
 
-    /// Métodos adicionales para formas circulares
-    pub fn draw_circle(&mut self, cx: usize, cy: usize, radius: usize, color: u32) {
-        let hex_color = color;
-        let radius = radius as isize;
+    // Región de la ventana donde se presenta el buffer lógico
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
 
-        let r2 = radius * radius;
+    // Acceso controlado al estado de la ventana
+    pub fn is_open(&self) -> bool {
+        self.window.is_open()
+    }
+}
 
-        let width = self.width as isize;
-        let height = self.height as isize;
+impl Canvas for ConfigGalar<'_> {
+    fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
 
-        for y in -radius..=radius {
-            for x in -radius..=radius {
-                if x * x + y * y <= r2 {
-                    let px = cx as isize + x;
-                    let py = cy as isize + y;
-                    if px >= 0 && px < width && py >= 0 && py < height {
-                        self.buffer[(py as usize) * self.width + (px as usize)] = hex_color;
-                    }
-                }
-            }
-        }
+    fn get_pixel(&self, x: usize, y: usize) -> Option<u32> {
+        slice_get(self.buffer, self.width, self.height, x, y)
     }
 
-    /// WIREFRAME: Métodos adicionales para formas circulares
-    pub fn draw_circle_outline(&mut self, cx: isize, cy: isize, radius: isize, color: Color) {
-        let hex_color = color.to_hex();
-        let r2 = radius * radius;
-        let inner_r2 = (radius - 1) * (radius - 1);
-
-        let width = self.width as isize;
-        let height = self.height as isize;
-
-        for y in -radius..=radius {
-            for x in -radius..=radius {
-                let dist2 = x * x + y * y;
-                if dist2 <= r2 && dist2 >= inner_r2 {
-                    let px = cx + x;
-                    let py = cy + y;
-                    if px >= 0 && px < width && py >= 0 && py < height {
-                        self.buffer[(py as usize) * self.width + (px as usize)] = hex_color;
-                    }
-                }
-            }
-        }
+    fn put_pixel(&mut self, x: usize, y: usize, color: u32) {
+        slice_put(self.buffer, self.width, self.height, x, y, color);
     }
 
-    // Acceso controlado a las pulsaciones de teclas
-    pub fn is_key_down(&self, key: minifb::Key) -> bool {
-        self.window.is_key_down(key)
+    fn fill_span(&mut self, x0: usize, x1: usize, y: usize, color: u32) {
+        slice_fill_span(self.buffer, self.width, self.height, x0, x1, y, color);
     }
 
-    // Obtorga las posiciones logicas del Mouse
-    pub fn get_mouse_position(&mut self, mode: minifb::MouseMode) -> Option<(f32, f32)> {
-        self.window.get_mouse_pos(mode)
+    // minifb ignora el byte de alfa: el framebuffer se compone siempre como opaco
+    fn blend_pixel(&mut self, x: usize, y: usize, color: u32) {
+        if let Some(dst) = self.get_pixel(x, y) {
+            self.put_pixel(x, y, blend_over_opaque(dst, color));
+        }
     }
 
-    // Acceso controlado al estado de la ventana
-    pub fn is_open(&self) -> bool {
-        self.window.is_open()
+    fn camera(&self) -> Option<&Camera2D> {
+        Some(self.camera)
     }
 }
 
@@ -243,15 +281,28 @@ pub enum FrameMode {
     Paused,
 }
 
+// Máximo delta de tiempo entregado a los plugins, en segundos
+const MAX_DELTA_TIME: f32 = 0.25;
+
+// Cada cuánto se revisan los archivos cuando la recarga en caliente está activa
+const HOT_RELOAD_INTERVAL: Duration = Duration::from_millis(500);
+
 // Motor principal con mejor gestión de recursos
 pub struct Galar {
     window: Window,
     buffer: Vec<u32>,
-    clean: bool,
-    background: u32,
+    width: usize,
+    height: usize,
+    window_size: (usize, usize),
+    resize_policy: ResizePolicy,
+    scale_filter: ScaleFilter,
+    viewport: Viewport,
+    present: Vec<u32>,
+    present_columns: BlitColumns,
+    state: GalarState,
     plugins: Vec<Box<dyn PluginGalar>>,
     running: bool,
-    frame_mode: FrameMode,
+    hot_reload: bool,
 }
 
 impl Galar {
@@ -274,21 +325,102 @@ impl Galar {
         window_instance.set_target_fps(framerate);
 
         let buffer = vec![0u32; width * height];
+        let window_size = window_instance.get_size();
+
+        // Semilla de `--seed`/`GALAR_SEED` o al azar; se muestra al ejecutar
+        let rng = GalarRng::from_env();
+        set_thread_seed(thread_seed_for(rng.seed()));
 
         Ok(Self {
             window: window_instance,
             buffer,
-            clean: true,
-            background: 0,
+            width,
+            height,
+            window_size,
+            resize_policy: ResizePolicy::default(),
+            scale_filter: ScaleFilter::default(),
+            viewport: Viewport::compute(ResizePolicy::default(), (width, height), window_size),
+            present: Vec::new(),
+            present_columns: BlitColumns::default(),
+            state: GalarState {
+                clean: true,
+                background: 0,
+                frame_mode: FrameMode::Continuous,
+                camera: Camera2D::default(),
+                post: PostProcess::new(),
+                rng,
+                delta_time: 0.0,
+                time: 0.0,
+            },
             plugins: Vec::new(),
             running: false,
-            frame_mode: FrameMode::Continuous,
+            hot_reload: false,
         })
     }
 
     // Builder pattern para una API más fluida
     pub fn with_clean_pixels(mut self, clear: bool) -> Self {
-        self.clean = clear;
+        self.state.clean = clear;
+        self
+    }
+
+    // Define cómo reaccionar cuando la ventana cambia de tamaño
+    pub fn with_resize_policy(mut self, policy: ResizePolicy) -> Self {
+        self.resize_policy = policy;
+        self.update_viewport();
+        self
+    }
+
+    // Lienzo lógico de baja resolución, independiente del tamaño de la ventana.
+    // Si la política era `Reallocate` pasa a `IntegerScale` para no perder el lienzo.
+    pub fn with_virtual_resolution(mut self, width: usize, height: usize) -> Self {
+        self.width = width;
+        self.height = height;
+        self.buffer = vec![self.state.background; width * height];
+        if self.resize_policy == ResizePolicy::Reallocate {
+            self.resize_policy = ResizePolicy::IntegerScale;
+        }
+        self.update_viewport();
+        self
+    }
+
+    // Cámara inicial usada por `draw_shape`
+    pub fn with_camera(mut self, camera: Camera2D) -> Self {
+        self.state.camera = camera;
+        self
+    }
+
+    // Recarga las texturas de `Assets::global` cuando cambian en disco
+    pub fn with_hot_reload(mut self, enabled: bool) -> Self {
+        self.hot_reload = enabled;
+        self
+    }
+
+    // Semilla por defecto del sketch; `--seed` y `GALAR_SEED` tienen prioridad
+    // para poder regenerar otra obra desde la línea de comandos.
+    // Debe ir antes de `add_plugin` para que `init` ya use la secuencia reproducible.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        if GalarRng::seed_from_env().is_none() {
+            self.state.rng.reseed(seed);
+            set_thread_seed(thread_seed_for(seed));
+        }
+        self
+    }
+
+    // Semilla actual del generador de Galar
+    pub fn seed(&self) -> u64 {
+        self.state.rng.seed()
+    }
+
+    // Añade un efecto al final de la cadena de post-proceso
+    pub fn with_post_effect<E: PostEffect>(mut self, effect: E) -> Self {
+        self.state.post.push(effect);
+        self
+    }
+
+    // Filtro para escalar el lienzo lógico a la ventana
+    pub fn with_scale_filter(mut self, filter: ScaleFilter) -> Self {
+        self.scale_filter = filter;
         self
     }
 
@@ -312,7 +444,7 @@ impl Galar {
 
     // Método para ejecutar una vez el dibujo
     pub fn nonloop(&mut self) {
-        self.frame_mode = FrameMode::SingleStep;
+        self.state.frame_mode = FrameMode::SingleStep;
     }
 
     // Bucle principal con mejor manejo de errores
@@ -322,17 +454,36 @@ impl Galar {
             return Err("No plugins added. Add at least one plugin before running.".to_string());
         }
 
-        let (width, height) = self.window.get_size();
+        let seed = self.state.rng.seed();
+        println!("🎲 Semilla: {seed} (repítela con {SEED_ARG} {seed} o {SEED_ENV}={seed})");
+
         self.running = true;
+        let start = Instant::now();
+        let mut last_frame = start;
+        let mut last_reload_check = start;
 
         // Bucle principal
         while self.running && self.window.is_open() && !self.window.is_key_down(minifb::Key::Escape)
         {
-            match self.frame_mode {
+            // Se limita el delta para que una pausa larga (mover la ventana) no dispare saltos
+            let now = Instant::now();
+            self.state.delta_time = (now - last_frame).as_secs_f32().min(MAX_DELTA_TIME);
+            self.state.time = (now - start).as_secs_f32();
+            last_frame = now;
+
+            // Recarga entre frames, nunca a mitad de un dibujo
+            if self.hot_reload && now - last_reload_check >= HOT_RELOAD_INTERVAL {
+                last_reload_check = now;
+                Self::reload_assets();
+            }
+
+            self.handle_resize();
+
+            match self.state.frame_mode {
                 FrameMode::Continuous => self.update_all(),
                 FrameMode::SingleStep => {
                     self.update_all();
-                    self.frame_mode = FrameMode::Paused;
+                    self.state.frame_mode = FrameMode::Paused;
 
                     println!("🟡 Frame único completado.");
                 }
@@ -341,19 +492,17 @@ impl Galar {
                 }
             }
 
-            // Actualizar la ventana con el buffer
-            self.window
-                .update_with_buffer(&self.buffer, width, height)
-                .map_err(|e| format!("Failed to update window: {}", e))?;
+            // Aplicar el post-proceso y actualizar la ventana con el buffer
+            self.present()?;
         }
 
         // Limpiar recursos de plugins
         let mut config = ConfigGalar::new(
             &mut self.window,
             &mut self.buffer,
-            &mut self.clean,
-            &mut self.background,
-            &mut self.frame_mode,
+            (self.width, self.height),
+            self.viewport,
+            &mut self.state,
         );
 
         for plugin in self.plugins.iter_mut() {
@@ -368,9 +517,9 @@ impl Galar {
         let mut config = ConfigGalar::new(
             &mut self.window,
             &mut self.buffer,
-            &mut self.clean,
-            &mut self.background,
-            &mut self.frame_mode,
+            (self.width, self.height),
+            self.viewport,
+            &mut self.state,
         );
 
         // Limpiar buffer si es necesario
@@ -381,6 +530,94 @@ impl Galar {
             plugin.update(&mut config);
         }
     }
+
+    fn reload_assets() {
+        let events = Assets::global()
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .poll_changes();
+
+        for event in events {
+            match event {
+                AssetEvent::Reloaded(path) => println!("🔄 Textura recargada: {}", path.display()),
+                AssetEvent::Failed(path, error) => {
+                    eprintln!("Failed to reload {}: {}", path.display(), error)
+                }
+            }
+        }
+    }
+
+    fn handle_resize(&mut self) {
+        let window_size = self.window.get_size();
+        if window_size == self.window_size || window_size.0 == 0 || window_size.1 == 0 {
+            return;
+        }
+        self.window_size = window_size;
+        let (width, height) = window_size;
+
+        if self.resize_policy == ResizePolicy::Reallocate {
+            self.width = width;
+            self.height = height;
+            self.buffer.clear();
+            self.buffer.resize(width * height, self.state.background);
+
+            // El contenido anterior se perdió, un frame pausado debe redibujarse
+            if let FrameMode::Paused = self.state.frame_mode {
+                self.state.frame_mode = FrameMode::SingleStep;
+            }
+        }
+
+        self.update_viewport();
+
+        let mut config = ConfigGalar::new(
+            &mut self.window,
+            &mut self.buffer,
+            (self.width, self.height),
+            self.viewport,
+            &mut self.state,
+        );
+
+        for plugin in self.plugins.iter_mut() {
+            plugin.resize(&mut config, width, height);
+        }
+    }
+
+    fn update_viewport(&mut self) {
+        self.viewport =
+            Viewport::compute(self.resize_policy, (self.width, self.height), self.window_size);
+    }
+
+    fn present(&mut self) -> Result<(), String> {
+        let logical = (self.width, self.height);
+        let window = self.window_size;
+        let viewport = self.viewport;
+
+        // El post-proceso trabaja sobre una copia: el buffer de los plugins queda intacto
+        let frame = if self.state.post.is_active() {
+            self.state.post.process(&self.buffer, self.width, self.height)
+        } else {
+            &self.buffer
+        };
+
+        let result = if viewport.width == 0 || viewport.is_identity(logical, window) {
+            self.window.update_with_buffer(frame, self.width, self.height)
+        } else {
+            self.present.resize(window.0 * window.1, 0);
+            blit_scaled(
+                frame,
+                logical,
+                &mut self.present,
+                window,
+                viewport,
+                self.scale_filter,
+                self.state.background,
+                &mut self.present_columns,
+            );
+            self.window.update_with_buffer(&self.present, window.0, window.1)
+        };
+
+        result.map_err(|e| format!("Failed to update window: {}", e))
+    }
 }
 
 // Implementación de Drop para limpieza segura
@@ -391,9 +628,9 @@ impl Drop for Galar {
             let mut config = ConfigGalar::new(
                 &mut self.window,
                 &mut self.buffer,
-                &mut self.clean,
-                &mut self.background,
-                &mut self.frame_mode,
+                (self.width, self.height),
+                self.viewport,
+                &mut self.state,
             );
 
             for plugin in self.plugins.iter_mut() {
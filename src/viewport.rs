@@ -0,0 +1,284 @@
+/// Política a seguir cuando la ventana cambia de tamaño
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizePolicy {
+    /// El buffer sigue a la ventana: se realoja con el nuevo tamaño
+    Reallocate,
+    /// Resolución lógica fija, estirada a toda la ventana
+    #[default]
+    Stretch,
+    /// Resolución lógica fija, conserva la proporción con bandas en los bordes
+    Letterbox,
+    /// Resolución lógica fija, escalada solo por múltiplos enteros y centrada
+    IntegerScale,
+}
+
+/// Filtro usado al escalar el buffer lógico a la ventana
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScaleFilter {
+    /// Vecino más cercano, píxeles nítidos (ideal para pixel-art)
+    #[default]
+    Nearest,
+    /// Interpolación bilineal, bordes suaves
+    Bilinear,
+}
+
+/// Rectángulo de la ventana donde se presenta el buffer lógico
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Viewport {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Viewport {
+    /// Calcula dónde cae el buffer lógico `logical` dentro de una ventana `window`
+    pub fn compute(policy: ResizePolicy, logical: (usize, usize), window: (usize, usize)) -> Self {
+        let (lw, lh) = logical;
+        let (ww, wh) = window;
+
+        if lw == 0 || lh == 0 || ww == 0 || wh == 0 {
+            return Self::default();
+        }
+
+        let (width, height) = match policy {
+            ResizePolicy::Reallocate | ResizePolicy::Stretch => (ww, wh),
+            ResizePolicy::Letterbox => {
+                // Escala máxima que cabe en ambos ejes
+                if ww * lh <= wh * lw {
+                    (ww, (lh * ww / lw).max(1))
+                } else {
+                    ((lw * wh / lh).max(1), wh)
+                }
+            }
+            ResizePolicy::IntegerScale => {
+                let scale = (ww / lw).min(wh / lh);
+                if scale == 0 {
+                    // Ventana más pequeña que el lienzo: mejor reducir que no mostrar nada
+                    return Self::compute(ResizePolicy::Letterbox, logical, window);
+                }
+                (lw * scale, lh * scale)
+            }
+        };
+
+        Self {
+            x: (ww - width) / 2,
+            y: (wh - height) / 2,
+            width,
+            height,
+        }
+    }
+
+    /// Indica si el viewport cubre la ventana 1:1 con el buffer lógico
+    pub fn is_identity(&self, logical: (usize, usize), window: (usize, usize)) -> bool {
+        self.x == 0 && self.y == 0 && (self.width, self.height) == logical && logical == window
+    }
+
+    /// Indica si un punto de la ventana cae dentro del viewport
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        x >= self.x as f32
+            && y >= self.y as f32
+            && x < (self.x + self.width) as f32
+            && y < (self.y + self.height) as f32
+    }
+
+    /// Convierte coordenadas de ventana a píxeles lógicos (sin recortar)
+    pub fn window_to_logical(&self, (x, y): (f32, f32), (lw, lh): (usize, usize)) -> (f32, f32) {
+        if self.width == 0 || self.height == 0 {
+            return (x, y);
+        }
+        (
+            (x - self.x as f32) * lw as f32 / self.width as f32,
+            (y - self.y as f32) * lh as f32 / self.height as f32,
+        )
+    }
+
+    /// Convierte píxeles lógicos a coordenadas de ventana
+    pub fn logical_to_window(&self, (x, y): (f32, f32), (lw, lh): (usize, usize)) -> (f32, f32) {
+        if lw == 0 || lh == 0 {
+            return (x, y);
+        }
+        (
+            x * self.width as f32 / lw as f32 + self.x as f32,
+            y * self.height as f32 / lh as f32 + self.y as f32,
+        )
+    }
+}
+
+/// Columnas de origen de cada columna del viewport. Solo dependen del viewport,
+/// del ancho lógico y del filtro, así que se guardan entre frames.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BlitColumns {
+    key: Option<(Viewport, usize, ScaleFilter)>,
+    nearest: Vec<usize>,
+    bilinear: Vec<(usize, usize, f32)>,
+}
+
+impl BlitColumns {
+    // Recalcula la tabla solo si cambió el viewport, el ancho lógico o el filtro
+    fn update(&mut self, viewport: Viewport, sw: usize, filter: ScaleFilter) {
+        let key = (viewport, sw, filter);
+        if self.key == Some(key) {
+            return;
+        }
+        self.key = Some(key);
+        self.nearest.clear();
+        self.bilinear.clear();
+        match filter {
+            ScaleFilter::Nearest => self
+                .nearest
+                .extend((0..viewport.width).map(|x| (x * sw / viewport.width).min(sw - 1))),
+            ScaleFilter::Bilinear => self
+                .bilinear
+                .extend((0..viewport.width).map(|x| bilinear_sample(x, sw, viewport.width))),
+        }
+    }
+}
+
+/// Copia `src` (tamaño lógico) a `dst` (tamaño de ventana) con el filtro dado,
+/// rellenando con `background` lo que queda fuera del viewport.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn blit_scaled(
+    src: &[u32],
+    src_size: (usize, usize),
+    dst: &mut [u32],
+    dst_size: (usize, usize),
+    viewport: Viewport,
+    filter: ScaleFilter,
+    background: u32,
+    columns: &mut BlitColumns,
+) {
+    dst.fill(background);
+
+    if viewport.width == 0 || viewport.height == 0 {
+        return;
+    }
+
+    columns.update(viewport, src_size.0, filter);
+    match filter {
+        ScaleFilter::Nearest => {
+            blit_nearest(src, src_size, dst, dst_size.0, viewport, &columns.nearest)
+        }
+        ScaleFilter::Bilinear => {
+            blit_bilinear(src, src_size, dst, dst_size.0, viewport, &columns.bilinear)
+        }
+    }
+}
+
+fn blit_nearest(
+    src: &[u32],
+    (sw, sh): (usize, usize),
+    dst: &mut [u32],
+    dw: usize,
+    viewport: Viewport,
+    columns: &[usize],
+) {
+    for y in 0..viewport.height {
+        let sy = (y * sh / viewport.height).min(sh - 1);
+        let src_row = &src[sy * sw..(sy + 1) * sw];
+        let start = (viewport.y + y) * dw + viewport.x;
+        let dst_row = &mut dst[start..start + viewport.width];
+
+        for (pixel, &sx) in dst_row.iter_mut().zip(columns) {
+            *pixel = src_row[sx];
+        }
+    }
+}
+
+// Muestreo en el centro del píxel: (i + 0.5) * escala - 0.5
+fn bilinear_sample(i: usize, size: usize, span: usize) -> (usize, usize, f32) {
+    let pos = ((i as f32 + 0.5) * size as f32 / span as f32 - 0.5).max(0.0);
+    let i0 = (pos as usize).min(size - 1);
+    let i1 = (i0 + 1).min(size - 1);
+    (i0, i1, pos - i0 as f32)
+}
+
+fn blit_bilinear(
+    src: &[u32],
+    (sw, sh): (usize, usize),
+    dst: &mut [u32],
+    dw: usize,
+    viewport: Viewport,
+    columns: &[(usize, usize, f32)],
+) {
+    for y in 0..viewport.height {
+        let (y0, y1, ty) = bilinear_sample(y, sh, viewport.height);
+        let row0 = &src[y0 * sw..(y0 + 1) * sw];
+        let row1 = &src[y1 * sw..(y1 + 1) * sw];
+        let start = (viewport.y + y) * dw + viewport.x;
+        let dst_row = &mut dst[start..start + viewport.width];
+
+        for (pixel, &(x0, x1, tx)) in dst_row.iter_mut().zip(columns) {
+            let top = lerp_pixel(row0[x0], row0[x1], tx);
+            let bottom = lerp_pixel(row1[x0], row1[x1], tx);
+            *pixel = lerp_pixel(top, bottom, ty);
+        }
+    }
+}
+
+#[inline(always)]
+fn lerp_pixel(a: u32, b: u32, t: f32) -> u32 {
+    let mut out = 0;
+    for shift in [0, 8, 16, 24] {
+        let ca = ((a >> shift) & 0xFF) as f32;
+        let cb = ((b >> shift) & 0xFF) as f32;
+        out |= ((ca + (cb - ca) * t).round() as u32) << shift;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn columns_are_cached_per_viewport() {
+        let src: Vec<u32> = (0..4 * 2).collect();
+        let mut dst = vec![0; 8 * 4];
+        let viewport = Viewport::compute(ResizePolicy::Stretch, (4, 2), (8, 4));
+        let mut columns = BlitColumns::default();
+
+        blit_scaled(
+            &src,
+            (4, 2),
+            &mut dst,
+            (8, 4),
+            viewport,
+            ScaleFilter::Nearest,
+            9,
+            &mut columns,
+        );
+        assert_eq!(&dst[..8], &[0, 0, 1, 1, 2, 2, 3, 3]);
+        assert_eq!(&dst[24..], &[4, 4, 5, 5, 6, 6, 7, 7]);
+        assert_eq!(columns.nearest, [0, 0, 1, 1, 2, 2, 3, 3]);
+
+        // Otro viewport con el mismo lienzo reconstruye la tabla
+        let letterbox = Viewport::compute(ResizePolicy::Letterbox, (4, 2), (4, 4));
+        blit_scaled(
+            &src,
+            (4, 2),
+            &mut dst,
+            (4, 4),
+            letterbox,
+            ScaleFilter::Nearest,
+            9,
+            &mut columns,
+        );
+        assert_eq!(columns.nearest, [0, 1, 2, 3]);
+        assert_eq!(&dst[..4], &[9; 4]);
+        assert_eq!(&dst[4..8], &[0, 1, 2, 3]);
+
+        blit_scaled(
+            &src,
+            (4, 2),
+            &mut dst,
+            (4, 4),
+            letterbox,
+            ScaleFilter::Bilinear,
+            9,
+            &mut columns,
+        );
+        assert_eq!(columns.bilinear.len(), 4);
+        assert_eq!(&dst[4..8], &[0, 1, 2, 3]);
+    }
+}